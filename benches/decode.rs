@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use morse_lib::{Alphabet, Morse, MorseTrie, MorseUnit};
+
+const TEXT: &str = "the quick brown fox jumps over the lazy dog 0123456789 ";
+
+fn decode(c: &mut Criterion) {
+    let bin = Morse::from_int_text(&TEXT.repeat(100)).to_bin_str();
+    let alphabet = Alphabet::default();
+    let trie = MorseTrie::from(&alphabet);
+    let codes: Vec<Vec<MorseUnit>> = alphabet
+        .symbols()
+        .into_iter()
+        .filter_map(|letter| alphabet.encode(letter))
+        .collect();
+    let map: HashMap<Vec<MorseUnit>, char> = alphabet
+        .symbols()
+        .into_iter()
+        .filter_map(|letter| Some((alphabet.encode(letter)?, letter)))
+        .collect();
+
+    c.bench_function("morse_parse_bin", |b| {
+        b.iter(|| Morse::from_int_bin(black_box(&bin)).to_text())
+    });
+    c.bench_function("trie_decode_bin", |b| {
+        b.iter(|| trie.decode_bin(black_box(&bin)).unwrap())
+    });
+    c.bench_function("hash_map_codes", |b| {
+        b.iter(|| {
+            codes
+                .iter()
+                .filter_map(|m_char| map.get(black_box(m_char.as_slice())))
+                .count()
+        })
+    });
+    c.bench_function("trie_codes", |b| {
+        b.iter(|| {
+            codes
+                .iter()
+                .filter_map(|m_char| trie.decode(black_box(m_char)))
+                .count()
+        })
+    });
+}
+
+criterion_group!(benches, decode);
+criterion_main!(benches);
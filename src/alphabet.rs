@@ -0,0 +1,217 @@
+use std::sync::Arc;
+
+use crate::{international, morse_alphabet::probe_symbols};
+use crate::{Language, MorseAlphabet, MorseTable, MorseUnit, Prosign};
+
+/// ## Conversion rules between characters and Morse Code.
+///
+/// Could be built from [MorseTable], from any [MorseAlphabet] implementation
+/// or from two functions:
+/// - first that match conversion from character to Morse Code
+/// - second that match conversion from Morse Code to Character
+#[derive(Debug, Clone)]
+pub enum Alphabet {
+    Converters {
+        from_char: fn(char) -> Option<Vec<MorseUnit>>,
+        into_char: fn(Vec<MorseUnit>) -> Option<char>,
+    },
+    Table(Arc<MorseTable>),
+    Custom(Arc<dyn MorseAlphabet>),
+}
+
+impl Alphabet {
+    /// Creates alphabet from conversion functions.
+    pub fn converters(
+        from_char: fn(char) -> Option<Vec<MorseUnit>>,
+        into_char: fn(Vec<MorseUnit>) -> Option<char>,
+    ) -> Alphabet {
+        Alphabet::Converters {
+            from_char,
+            into_char,
+        }
+    }
+
+    /// Creates alphabet from conversion rules that could keep own state,
+    /// e.g. closures or lookup table loaded at runtime.
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use morse_lib::{Alphabet, Morse, MorseUnit};
+    /// use MorseUnit::{Dot, Line};
+    ///
+    /// let substitution = HashMap::from([('ä', 'a')]);
+    /// let from_char = move |letter: char| match substitution.get(&letter).unwrap_or(&letter) {
+    ///     'a' => Some(vec![Dot, Line]),
+    ///     _ => None,
+    /// };
+    /// let into_char = |m_char: Vec<MorseUnit>| (m_char == [Dot, Line]).then_some('a');
+    ///
+    /// let mut morse = Morse::with_alphabet("A".to_string(), Alphabet::custom((from_char, into_char)));
+    /// morse.push_text("aä");
+    ///
+    /// assert_eq!(morse.to_bin_str(), "1011100010111");
+    /// ```
+    pub fn custom(alphabet: impl MorseAlphabet + 'static) -> Alphabet {
+        Alphabet::Custom(Arc::new(alphabet))
+    }
+
+    /// Return Morse Code of character.
+    pub fn encode(&self, letter: char) -> Option<Vec<MorseUnit>> {
+        match self {
+            Alphabet::Converters { from_char, .. } => from_char(letter),
+            Alphabet::Table(table) => table.encode(letter),
+            Alphabet::Custom(alphabet) => alphabet.encode(letter),
+        }
+    }
+
+    /// Return character of Morse Code.
+    pub fn decode(&self, m_char: &[MorseUnit]) -> Option<char> {
+        match self {
+            Alphabet::Converters { into_char, .. } => into_char(m_char.to_vec()),
+            Alphabet::Table(table) => table.decode(m_char),
+            Alphabet::Custom(alphabet) => alphabet.decode(m_char),
+        }
+    }
+
+    /// Return all characters that have Morse Code.
+    /// Conversion functions are asked for every code of up to 7 dots and lines.
+    pub fn symbols(&self) -> Vec<char> {
+        match self {
+            Alphabet::Converters { into_char, .. } => probe_symbols(into_char),
+            Alphabet::Table(table) => table.symbols(),
+            Alphabet::Custom(alphabet) => alphabet.symbols(),
+        }
+    }
+
+    /// Return Morse Code of prosign.
+    pub fn encode_prosign(&self, prosign: Prosign) -> Vec<MorseUnit> {
+        match self {
+            Alphabet::Converters { .. } | Alphabet::Custom(_) => prosign.code(),
+            Alphabet::Table(table) => table.encode_prosign(prosign),
+        }
+    }
+
+    /// Return prosign of Morse Code.
+    pub fn decode_prosign(&self, m_char: &[MorseUnit]) -> Option<Prosign> {
+        match self {
+            Alphabet::Converters { .. } | Alphabet::Custom(_) => Prosign::from_code(m_char),
+            Alphabet::Table(table) => table.decode_prosign(m_char),
+        }
+    }
+
+    /// Return length in characters and Morse Code of digraph at the start of text.
+    pub fn encode_digraph(&self, letters: &[char]) -> Option<(usize, Vec<MorseUnit>)> {
+        match self {
+            Alphabet::Converters { .. } | Alphabet::Custom(_) => None,
+            Alphabet::Table(table) => table.encode_digraph(letters),
+        }
+    }
+
+    /// Return digraph of Morse Code.
+    pub fn decode_digraph(&self, m_char: &[MorseUnit]) -> Option<&str> {
+        match self {
+            Alphabet::Converters { .. } | Alphabet::Custom(_) => None,
+            Alphabet::Table(table) => table.decode_digraph(m_char),
+        }
+    }
+
+    /// Split character without own code into characters of the alphabet.
+    pub fn decompose(&self, letter: char) -> Option<String> {
+        match self {
+            Alphabet::Converters { .. } | Alphabet::Custom(_) => None,
+            Alphabet::Table(table) => table.decompose(letter),
+        }
+    }
+
+    /// Join decoded characters.
+    pub fn compose(&self, text: &str) -> String {
+        match self {
+            Alphabet::Converters { .. } | Alphabet::Custom(_) => text.to_string(),
+            Alphabet::Table(table) => table.compose(text),
+        }
+    }
+
+    /// Return language that prosign switches to.
+    pub fn shift_of(&self, prosign: Prosign) -> Option<Language> {
+        match self {
+            Alphabet::Converters { .. } | Alphabet::Custom(_) => None,
+            Alphabet::Table(table) => table.shift_of(prosign),
+        }
+    }
+
+    /// Return all prosigns that switch language.
+    pub fn shifts(&self) -> &[(Prosign, Language)] {
+        match self {
+            Alphabet::Converters { .. } | Alphabet::Custom(_) => &[],
+            Alphabet::Table(table) => table.shifts(),
+        }
+    }
+}
+
+impl MorseAlphabet for Alphabet {
+    fn encode(&self, letter: char) -> Option<Vec<MorseUnit>> {
+        Alphabet::encode(self, letter)
+    }
+
+    fn decode(&self, m_char: &[MorseUnit]) -> Option<char> {
+        Alphabet::decode(self, m_char)
+    }
+
+    fn symbols(&self) -> Vec<char> {
+        Alphabet::symbols(self)
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Alphabet::Table(international())
+    }
+}
+
+impl From<MorseTable> for Alphabet {
+    fn from(table: MorseTable) -> Self {
+        Alphabet::Table(Arc::new(table))
+    }
+}
+
+impl From<Arc<MorseTable>> for Alphabet {
+    fn from(table: Arc<MorseTable>) -> Self {
+        Alphabet::Table(table)
+    }
+}
+
+impl From<Box<dyn MorseAlphabet>> for Alphabet {
+    fn from(alphabet: Box<dyn MorseAlphabet>) -> Self {
+        Alphabet::Custom(Arc::from(alphabet))
+    }
+}
+
+impl From<Arc<dyn MorseAlphabet>> for Alphabet {
+    fn from(alphabet: Arc<dyn MorseAlphabet>) -> Self {
+        Alphabet::Custom(alphabet)
+    }
+}
+
+// Addresses of functions are not stable, so conversion functions are equal
+// when they encode and decode the same characters
+impl PartialEq for Alphabet {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Alphabet::Converters { .. }, Alphabet::Converters { .. }) => {
+                let symbols = self.symbols();
+                symbols == other.symbols()
+                    && symbols
+                        .iter()
+                        .all(|letter| self.encode(*letter) == other.encode(*letter))
+            }
+            (Alphabet::Table(table), Alphabet::Table(other_table)) => {
+                Arc::ptr_eq(table, other_table) || table == other_table
+            }
+            (Alphabet::Custom(alphabet), Alphabet::Custom(other_alphabet)) => {
+                Arc::ptr_eq(alphabet, other_alphabet)
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,463 @@
+//! Alphabets loaded from TOML or JSON files.
+//!
+//! File contains language label, characters and prosigns with their Morse Code
+//! written by dots and dashes:
+//!
+//! ```toml
+//! language = "Ukrainian"
+//!
+//! [letters]
+//! "а" = ".-"
+//! "б" = "-..."
+//!
+//! [prosigns]
+//! AR = ".-.-."
+//! ```
+//!
+//! Space between words is added automatically, if it isn't defined.
+
+use std::{fs, path::Path};
+
+use crate::{Morse, MorseError, MorseTable, MorseUnit, Prosign};
+
+struct Entry {
+    key: String,
+    code: String,
+    line: usize,
+}
+
+struct AlphabetFile {
+    language: String,
+    letters: Vec<Entry>,
+    prosigns: Vec<Entry>,
+}
+
+impl Morse {
+    /// Creates Morse Code struct with alphabet from file.
+    /// Format is chosen by extension: `.toml` or `.json`.
+    ///
+    /// Position in the error is the line of the file, or 0 when the file can't be read.
+    pub fn load_alphabet(path: impl AsRef<Path>) -> Result<Morse, MorseError> {
+        let path = path.as_ref();
+        let parse: fn(&str) -> Result<Morse, MorseError> =
+            match path.extension().and_then(|extension| extension.to_str()) {
+                #[cfg(feature = "toml")]
+                Some("toml") => Morse::from_toml_alphabet,
+                #[cfg(feature = "json")]
+                Some("json") => Morse::from_json_alphabet,
+                _ => {
+                    return Err(MorseError::InvalidAlphabet {
+                        message: format!("unsupported alphabet file {}", path.display()),
+                        position: 0,
+                    })
+                }
+            };
+        let source = fs::read_to_string(path).map_err(|err| MorseError::InvalidAlphabet {
+            message: err.to_string(),
+            position: 0,
+        })?;
+
+        parse(&source)
+    }
+
+    /// Creates Morse Code struct with alphabet from TOML.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::Morse;
+    ///
+    /// let mut morse = Morse::from_toml_alphabet(r#"
+    ///     language = "Ukrainian"
+    ///
+    ///     [letters]
+    ///     "а" = ".-"
+    ///     "б" = "-..."
+    /// "#).unwrap();
+    /// morse.push_text("баба");
+    ///
+    /// assert_eq!(morse.get_language(), "Ukrainian");
+    /// assert_eq!(morse.to_string(), "⚊ . . .   . ⚊   ⚊ . . .   . ⚊");
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml_alphabet(source: &str) -> Result<Morse, MorseError> {
+        AlphabetFile::from_toml(source)?.into_morse()
+    }
+
+    /// Creates Morse Code struct with alphabet from JSON.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::Morse;
+    ///
+    /// let mut morse = Morse::from_json_alphabet(r#"{
+    ///     "language": "Ukrainian",
+    ///     "letters": { "а": ".-", "б": "-..." }
+    /// }"#).unwrap();
+    /// morse.push_text("баба");
+    ///
+    /// assert_eq!(morse.to_string(), "⚊ . . .   . ⚊   ⚊ . . .   . ⚊");
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn from_json_alphabet(source: &str) -> Result<Morse, MorseError> {
+        AlphabetFile::from_json(source)?.into_morse()
+    }
+}
+
+impl AlphabetFile {
+    #[cfg(feature = "toml")]
+    fn from_toml(source: &str) -> Result<AlphabetFile, MorseError> {
+        use std::collections::BTreeMap;
+
+        use serde::Deserialize;
+        use toml::Spanned;
+
+        type Codes = BTreeMap<Spanned<String>, Spanned<String>>;
+
+        #[derive(Deserialize)]
+        struct TomlAlphabet {
+            language: String,
+            letters: Codes,
+            #[serde(default)]
+            prosigns: Codes,
+        }
+
+        let entries = |codes: Codes| -> Vec<Entry> {
+            let mut entries: Vec<Entry> = codes
+                .into_iter()
+                .map(|(key, code)| Entry {
+                    line: line_at(source, key.span().start),
+                    key: key.into_inner(),
+                    code: code.into_inner(),
+                })
+                .collect();
+            entries.sort_by_key(|entry| entry.line);
+
+            entries
+        };
+
+        let alphabet: TomlAlphabet =
+            toml::from_str(source).map_err(|err| MorseError::InvalidAlphabet {
+                message: err.message().to_string(),
+                position: err.span().map_or(0, |span| line_at(source, span.start)),
+            })?;
+
+        Ok(AlphabetFile {
+            language: alphabet.language,
+            letters: entries(alphabet.letters),
+            prosigns: entries(alphabet.prosigns),
+        })
+    }
+
+    #[cfg(feature = "json")]
+    fn from_json(source: &str) -> Result<AlphabetFile, MorseError> {
+        use std::fmt;
+
+        use serde::{
+            de::{Deserializer, Error, MapAccess, Visitor},
+            Deserialize,
+        };
+
+        // Codes in order of the file. JSON allows repeated keys and map would keep
+        // only the last one, so repeated keys are rejected while reading
+        #[derive(Default)]
+        struct Codes(Vec<(String, String)>);
+
+        struct CodesVisitor;
+
+        impl<'de> Visitor<'de> for CodesVisitor {
+            type Value = Codes;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("map of characters to Morse Code")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Codes, A::Error> {
+                let mut codes: Vec<(String, String)> = Vec::new();
+                while let Some(key) = map.next_key::<String>()? {
+                    // Error is raised before the value, so parser reports line of the key
+                    if codes.iter().any(|(other, _)| *other == key) {
+                        return Err(A::Error::custom(format!("duplicate key {key:?}")));
+                    }
+                    let code = map.next_value()?;
+                    codes.push((key, code));
+                }
+
+                Ok(Codes(codes))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Codes {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Codes, D::Error> {
+                deserializer.deserialize_map(CodesVisitor)
+            }
+        }
+
+        #[derive(Deserialize)]
+        struct JsonAlphabet {
+            language: String,
+            letters: Codes,
+            #[serde(default)]
+            prosigns: Codes,
+        }
+
+        // JSON parser doesn't keep positions, so the line is the first occurrence
+        // of the key after the section name
+        let entries = |section: &str, codes: Codes| -> Vec<Entry> {
+            let quoted = |text: &str| serde_json::to_string(text).unwrap_or_default();
+            let section_start = source.find(&quoted(section)).unwrap_or(0);
+            let mut entries: Vec<Entry> = codes
+                .0
+                .into_iter()
+                .map(|(key, code)| Entry {
+                    line: source[section_start..]
+                        .find(&quoted(&key))
+                        .map_or(0, |offset| line_at(source, section_start + offset)),
+                    key,
+                    code,
+                })
+                .collect();
+            entries.sort_by_key(|entry| entry.line);
+
+            entries
+        };
+
+        let alphabet: JsonAlphabet =
+            serde_json::from_str(source).map_err(|err| MorseError::InvalidAlphabet {
+                message: err.to_string(),
+                position: err.line(),
+            })?;
+
+        Ok(AlphabetFile {
+            language: alphabet.language,
+            letters: entries("letters", alphabet.letters),
+            prosigns: entries("prosigns", alphabet.prosigns),
+        })
+    }
+
+    fn into_morse(self) -> Result<Morse, MorseError> {
+        let mut pairs = Vec::new();
+        let mut lines = Vec::new();
+
+        for entry in &self.letters {
+            let mut letters = entry.key.chars();
+            let letter = match (letters.next(), letters.next()) {
+                (Some(letter), None) => letter,
+                _ => {
+                    return Err(MorseError::InvalidAlphabet {
+                        message: format!("{:?} is not a single character", entry.key),
+                        position: entry.line,
+                    })
+                }
+            };
+            let m_char = parse_code(&entry.code).ok_or(MorseError::InvalidCode {
+                letter,
+                position: entry.line,
+            })?;
+
+            pairs.push((letter, m_char));
+            lines.push(entry.line);
+        }
+        if !pairs.iter().any(|(letter, _)| *letter == ' ') {
+            pairs.push((' ', vec![MorseUnit::Whitespace]));
+        }
+
+        // Table reports index of the pair, replace it by the line
+        let mut table = MorseTable::new(pairs).map_err(|err| {
+            let line = lines.get(err.position()).copied().unwrap_or(0);
+            err.at(line)
+        })?;
+
+        for entry in &self.prosigns {
+            let name = entry.key.trim_start_matches('<').trim_end_matches('>');
+            let prosign = Prosign::from_name(name).ok_or(MorseError::InvalidAlphabet {
+                message: format!("unknown prosign {:?}", entry.key),
+                position: entry.line,
+            })?;
+            let m_char = parse_code(&entry.code).ok_or(MorseError::InvalidAlphabet {
+                message: format!("invalid Morse Code of prosign {:?}", entry.key),
+                position: entry.line,
+            })?;
+            // Letters are decoded before prosigns, so prosign with code of letter is never read
+            if table.decode(&m_char).is_some() {
+                return Err(MorseError::DuplicateCode {
+                    code: m_char,
+                    position: entry.line,
+                });
+            }
+
+            table.prosign(prosign, m_char);
+        }
+
+        Ok(Morse::with_alphabet(self.language, table))
+    }
+}
+
+/// Parse code written by dots and dashes, e.g. `.-..`. Space is the gap inside letter.
+fn parse_code(code: &str) -> Option<Vec<MorseUnit>> {
+    code.chars()
+        .map(|unit| match unit {
+            '.' | '·' | '•' => Some(MorseUnit::Dot),
+            '-' | '−' | '–' | '_' | '⚊' => Some(MorseUnit::Line),
+            '⸺' => Some(MorseUnit::LongLine),
+            '⸻' => Some(MorseUnit::ExtraLongLine),
+            ' ' => Some(MorseUnit::Gap),
+            _ => None,
+        })
+        .collect()
+}
+
+fn line_at(source: &str, offset: usize) -> usize {
+    source[..offset].matches('\n').count() + 1
+}
+
+#[cfg(test)]
+mod alphabet_file_tests {
+    use super::*;
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn load_toml() {
+        let mut morse = Morse::from_toml_alphabet(
+            r#"
+language = "Test"
+
+[letters]
+"a" = ".-"
+"ж" = "...-"
+
+[prosigns]
+"<SK>" = "----"
+"#,
+        )
+        .unwrap();
+        morse.push_text("A ж<SK>");
+
+        assert_eq!(morse.get_language(), "Test");
+        assert_eq!(morse.to_string(), ". ⚊       . . . ⚊   ⚊ ⚊ ⚊ ⚊");
+
+        let mut decoded = morse.clone();
+        decoded.push_bin(&morse.to_bin_str());
+        assert_eq!(decoded.to_text(), "A ж<SK>a ж<SK>");
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_errors_point_to_line() {
+        let err = |source: &str| Morse::from_toml_alphabet(source).unwrap_err();
+
+        assert_eq!(
+            err("language = \"Test\"\n[letters]\n\"a\" = \".-\"\n\"b\" = \".-\"\n"),
+            MorseError::DuplicateCode {
+                code: vec![MorseUnit::Dot, MorseUnit::Line],
+                position: 4
+            }
+        );
+        assert_eq!(
+            err("language = \"Test\"\n[letters]\n\"a\" = \".-\"\n\"b\" = \".x\"\n"),
+            MorseError::InvalidCode {
+                letter: 'b',
+                position: 4
+            }
+        );
+        assert_eq!(
+            err("language = \"Test\"\n[letters]\n\"ab\" = \".-\"\n").position(),
+            3
+        );
+        assert_eq!(
+            err("language = \"Test\"\n[letters]\n[prosigns]\nXX = \".-\"\n").position(),
+            4
+        );
+        assert_eq!(
+            err("language = \"Test\"\n[letters]\n\"a\" = \".-\"\n[prosigns]\nSK = \".-\"\n"),
+            MorseError::DuplicateCode {
+                code: vec![MorseUnit::Dot, MorseUnit::Line],
+                position: 5
+            }
+        );
+        assert_eq!(err("language = \"Test\"\n[letters\n").position(), 2);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn load_json() {
+        let mut morse = Morse::from_json_alphabet(
+            r#"{
+    "language": "Test",
+    "letters": {
+        "a": ".-",
+        "ж": "...-"
+    },
+    "prosigns": { "SK": "----" }
+}"#,
+        )
+        .unwrap();
+        morse.push_text("a ж<SK>");
+
+        assert_eq!(morse.to_string(), ". ⚊       . . . ⚊   ⚊ ⚊ ⚊ ⚊");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_errors_point_to_line() {
+        let err = |source: &str| Morse::from_json_alphabet(source).unwrap_err();
+
+        assert_eq!(
+            err("{\n\"language\": \"Test\",\n\"letters\": {\n\"a\": \".-\",\n\"b\": \".-\"\n}\n}"),
+            MorseError::DuplicateCode {
+                code: vec![MorseUnit::Dot, MorseUnit::Line],
+                position: 5
+            }
+        );
+        assert_eq!(
+            err("{\n\"language\": \"Test\",\n\"letters\": {\n\"a\": \".-\",\n\"a\": \"-\"\n}\n}"),
+            MorseError::InvalidAlphabet {
+                message: "duplicate key \"a\" at line 5 column 3".to_string(),
+                position: 5
+            }
+        );
+        assert_eq!(
+            err("{\n\"language\": \"Test\",\n\"letters\": {\n").position(),
+            4
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn load_toml_file() {
+        let path = std::env::temp_dir().join("morse_lib_alphabet.toml");
+        fs::write(&path, "language = \"Test\"\n[letters]\n\"a\" = \".-\"\n").unwrap();
+        let morse = Morse::load_alphabet(&path);
+        fs::remove_file(&path).unwrap();
+
+        let mut morse = morse.unwrap();
+        morse.push_text("a a");
+        assert_eq!(morse.get_language(), "Test");
+        assert_eq!(morse.to_bin_str(), "10111000000010111");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn load_json_file() {
+        let path = std::env::temp_dir().join("morse_lib_alphabet.json");
+        fs::write(&path, r#"{ "language": "Test", "letters": { "a": ".-" } }"#).unwrap();
+        let morse = Morse::load_alphabet(&path);
+        fs::remove_file(&path).unwrap();
+
+        let mut morse = morse.unwrap();
+        morse.push_text("a a");
+        assert_eq!(morse.get_language(), "Test");
+        assert_eq!(morse.to_bin_str(), "10111000000010111");
+    }
+
+    #[test]
+    fn unsupported_file() {
+        // Extension is checked before reading, so the file doesn't have to exist
+        assert_eq!(
+            Morse::load_alphabet("alphabet.xml"),
+            Err(MorseError::InvalidAlphabet {
+                message: "unsupported alphabet file alphabet.xml".to_string(),
+                position: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use std::{f64::consts::PI, time::Duration};
+
+/// ## Shape of rise and fall of keyed tone.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum EnvelopeShape {
+    /// Half period of cosine, `(1 - cos(πx)) / 2`
+    RaisedCosine,
+    /// Half of Blackman window, narrower spectrum than raised cosine
+    /// with the same rise time
+    Blackman,
+}
+
+/// ## Keying envelope of every dot and dash.
+///
+/// Hard edges of tone produce clicks and splatter wide spectrum around its frequency,
+/// so tone rises and falls smoothly inside the element, durations of elements
+/// are not changed. Element shorter than rise and fall together is shaped
+/// with its half for each.
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use morse_lib::{Envelope, Morse};
+///
+/// let mut morse = Morse::from_int_text("e");
+/// morse.envelope(Envelope::blackman(Duration::from_millis(5)));
+/// let samples = morse.render_samples(8000);
+///
+/// assert_eq!(samples[0], 0.0);
+/// assert!(samples[1].abs() < 0.001);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Envelope {
+    pub shape: EnvelopeShape,
+    pub rise: Duration,
+    pub fall: Duration,
+}
+
+impl Envelope {
+    /// Creates raised-cosine envelope with the same rise and fall time.
+    pub fn raised_cosine(edge: Duration) -> Envelope {
+        Envelope {
+            shape: EnvelopeShape::RaisedCosine,
+            rise: edge,
+            fall: edge,
+        }
+    }
+
+    /// Creates Blackman envelope with the same rise and fall time.
+    pub fn blackman(edge: Duration) -> Envelope {
+        Envelope {
+            shape: EnvelopeShape::Blackman,
+            rise: edge,
+            fall: edge,
+        }
+    }
+
+    /// Creates envelope with hard edges, tone is switched instantly.
+    pub fn hard() -> Envelope {
+        Envelope::raised_cosine(Duration::ZERO)
+    }
+
+    /// Return gains of tone that is `len` samples long.
+    pub(crate) fn gains(self, len: usize, sample_rate: u32) -> impl Iterator<Item = f64> {
+        let edge = |duration: Duration| {
+            ((duration.as_secs_f64() * f64::from(sample_rate)).round() as usize).min(len / 2)
+        };
+        let (rise, fall) = (edge(self.rise), edge(self.fall));
+
+        (0..len).map(move |idx| {
+            if idx < rise {
+                self.shape(idx as f64 / rise as f64)
+            } else if len - 1 - idx < fall {
+                self.shape((len - 1 - idx) as f64 / fall as f64)
+            } else {
+                1.0
+            }
+        })
+    }
+
+    // Gain at part of edge from 0 to 1
+    fn shape(&self, x: f64) -> f64 {
+        match self.shape {
+            EnvelopeShape::RaisedCosine => 0.5 - 0.5 * (PI * x).cos(),
+            EnvelopeShape::Blackman => 0.42 - 0.5 * (PI * x).cos() + 0.08 * (2.0 * PI * x).cos(),
+        }
+    }
+}
+
+impl Default for Envelope {
+    fn default() -> Self {
+        Envelope::raised_cosine(Duration::from_millis(5))
+    }
+}
+
+#[cfg(test)]
+mod envelope_tests {
+    use super::*;
+    use crate::{Morse, Timing};
+
+    const SAMPLE_RATE: u32 = 4000;
+    const FREQUENCY: f32 = 1000.0;
+
+    // Part of power of ten dots that is farther than 400 Hz from tone frequency
+    fn splatter(envelope: Envelope) -> f64 {
+        let mut morse = Morse::from_int_text("eeeeeeeeee");
+        morse.frequency(FREQUENCY);
+        morse.timing(Timing::new(20.0));
+        morse.envelope(envelope);
+        let samples = morse.render_samples(SAMPLE_RATE);
+
+        // Discrete Fourier transform, phase of every bin is rotated by multiplication
+        let len = samples.len();
+        let (mut total, mut outside) = (0.0, 0.0);
+        for bin in 0..=len / 2 {
+            let step = 2.0 * PI * bin as f64 / len as f64;
+            let (step_re, step_im) = (step.cos(), -step.sin());
+            let (mut phase_re, mut phase_im) = (1.0, 0.0);
+            let (mut re, mut im) = (0.0, 0.0);
+            for sample in &samples {
+                re += f64::from(*sample) * phase_re;
+                im += f64::from(*sample) * phase_im;
+                (phase_re, phase_im) = (
+                    phase_re * step_re - phase_im * step_im,
+                    phase_re * step_im + phase_im * step_re,
+                );
+            }
+            let power = re * re + im * im;
+            let frequency = bin as f64 * f64::from(SAMPLE_RATE) / len as f64;
+
+            total += power;
+            if (frequency - f64::from(FREQUENCY)).abs() > 400.0 {
+                outside += power;
+            }
+        }
+
+        outside / total
+    }
+
+    #[test]
+    fn gains() {
+        let envelope = Envelope::raised_cosine(Duration::from_millis(1));
+        let gains: Vec<f64> = envelope.gains(20, SAMPLE_RATE).collect();
+
+        assert_eq!(gains[0], 0.0);
+        assert!((gains[2] - 0.5).abs() < 1e-9);
+        assert_eq!(gains[4..16], [1.0; 12]);
+        assert!((gains[17] - 0.5).abs() < 1e-9);
+        assert_eq!(gains[19], 0.0);
+        assert_eq!(
+            Envelope::hard().gains(3, SAMPLE_RATE).collect::<Vec<_>>(),
+            [1.0; 3]
+        );
+        // Edges of short tone take its halves
+        assert_eq!(
+            envelope.gains(3, SAMPLE_RATE).collect::<Vec<_>>(),
+            [0.0, 1.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn spectral_splatter() {
+        let hard = splatter(Envelope::hard());
+        let raised_cosine = splatter(Envelope::raised_cosine(Duration::from_millis(5)));
+        let blackman = splatter(Envelope::blackman(Duration::from_millis(5)));
+
+        // At least 25 dB less splatter
+        assert!(hard > 1e-3, "{hard}");
+        assert!(raised_cosine < hard / 316.0, "{raised_cosine} {hard}");
+        assert!(blackman < hard / 316.0, "{blackman} {hard}");
+    }
+}
@@ -0,0 +1,107 @@
+use std::{error::Error, fmt};
+
+use crate::MorseUnit;
+
+/// ## Errors of coding/decoding Morse Code.
+///
+/// Every variant, except [MorseError::DuplicateLanguage], keeps the position where
+/// the problem was found: for text it is index of the character, for binary it is index
+/// of the first bit of the failed group, for [MorseTable](crate::MorseTable) it is index
+/// of the pair, for alphabet file it is line, for [Registry](crate::Registry) it is 0.
+#[derive(Debug, PartialEq, Clone)]
+pub enum MorseError {
+    /// Character has no Morse Code representation in the used alphabet.
+    UnknownChar { letter: char, position: usize },
+    /// Sequence of Morse units doesn't match any character of the used alphabet.
+    UnknownCode {
+        code: Vec<MorseUnit>,
+        position: usize,
+    },
+    /// Bit group can't be parsed into Morse units.
+    InvalidBinary { group: String, position: usize },
+    /// Character is defined twice in the table.
+    DuplicateChar { letter: char, position: usize },
+    /// Morse Code is used by two characters of the table.
+    DuplicateCode {
+        code: Vec<MorseUnit>,
+        position: usize,
+    },
+    /// Character has empty code, code with whitespace inside or code with gap
+    /// after units that aren't a code of the table.
+    InvalidCode { letter: char, position: usize },
+    /// Alphabet file can't be read or parsed.
+    InvalidAlphabet { message: String, position: usize },
+    /// No alphabet is registered with the key.
+    UnknownLanguage { key: String, position: usize },
+    /// Alphabet is already registered with the key.
+    DuplicateLanguage { key: String },
+}
+
+impl MorseError {
+    /// Return position where error occurred, 0 for error without position.
+    pub fn position(&self) -> usize {
+        match self {
+            MorseError::UnknownChar { position, .. }
+            | MorseError::UnknownCode { position, .. }
+            | MorseError::InvalidBinary { position, .. }
+            | MorseError::DuplicateChar { position, .. }
+            | MorseError::DuplicateCode { position, .. }
+            | MorseError::InvalidCode { position, .. }
+            | MorseError::InvalidAlphabet { position, .. }
+            | MorseError::UnknownLanguage { position, .. } => *position,
+            MorseError::DuplicateLanguage { .. } => 0,
+        }
+    }
+
+    pub(crate) fn at(mut self, offset: usize) -> MorseError {
+        match &mut self {
+            MorseError::UnknownChar { position, .. }
+            | MorseError::UnknownCode { position, .. }
+            | MorseError::InvalidBinary { position, .. }
+            | MorseError::DuplicateChar { position, .. }
+            | MorseError::DuplicateCode { position, .. }
+            | MorseError::InvalidCode { position, .. }
+            | MorseError::InvalidAlphabet { position, .. }
+            | MorseError::UnknownLanguage { position, .. } => *position = offset,
+            MorseError::DuplicateLanguage { .. } => {}
+        }
+
+        self
+    }
+}
+
+impl fmt::Display for MorseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MorseError::UnknownChar { letter, position } => {
+                write!(f, "unknown character {letter:?} at position {position}")
+            }
+            MorseError::UnknownCode { code, position } => {
+                write!(f, "unknown Morse Code {code:?} at position {position}")
+            }
+            MorseError::InvalidBinary { group, position } => {
+                write!(f, "invalid binary group {group:?} at position {position}")
+            }
+            MorseError::DuplicateChar { letter, position } => {
+                write!(f, "duplicate character {letter:?} at position {position}")
+            }
+            MorseError::DuplicateCode { code, position } => {
+                write!(f, "duplicate Morse Code {code:?} at position {position}")
+            }
+            MorseError::InvalidCode { letter, position } => {
+                write!(f, "invalid Morse Code of {letter:?} at position {position}")
+            }
+            MorseError::InvalidAlphabet { message, position } => {
+                write!(f, "invalid alphabet at line {position}: {message}")
+            }
+            MorseError::UnknownLanguage { key, .. } => {
+                write!(f, "unknown language {key:?}")
+            }
+            MorseError::DuplicateLanguage { key } => {
+                write!(f, "language {key:?} is already registered")
+            }
+        }
+    }
+}
+
+impl Error for MorseError {}
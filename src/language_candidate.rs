@@ -0,0 +1,325 @@
+use std::cmp::Ordering;
+
+use crate::{
+    morse_char::{MorseChar, Symbol},
+    morse_processors::split_bin,
+    Language, Morse, MorseError, MorseUnit, Registry, RegistryEntry,
+};
+
+// Probability of code unknown in the language, far below probability of any letter
+const UNKNOWN_CODE: f32 = 1e-4;
+// Probability of digit, punctuation mark or prosign, the same in every language
+const OTHER_SYMBOL: f32 = 1e-2;
+// International Morse Code is sent far more often than national alphabets,
+// so before any code is seen it is that many times more probable than each of them
+const INTERNATIONAL_PRIOR: f32 = 10.0;
+
+/// ## Possible language of Morse Code.
+///
+/// Returned by [Morse::detect_language], contains text decoded with the alphabet
+/// and confidence from 0 to 1. Codes unknown in the alphabet are decoded as `�`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LanguageCandidate {
+    /// Registry key of the alphabet
+    pub key: String,
+    /// Language label
+    pub name: String,
+    pub text: String,
+    pub confidence: f32,
+}
+
+impl Morse {
+    /// Decode binary with every registered alphabet and rank them by confidence.
+    ///
+    /// Every code is weighted by its probability in the language: letters by their
+    /// place in [Language::frequent_letters], letters of registered alphabets
+    /// as any code of the same units and length, codes unknown in the alphabet
+    /// are almost impossible. International
+    /// Morse Code is more probable than national alphabets from the start. Confidence
+    /// is the share of the alphabet in probability of all of them, so confidences
+    /// of all candidates sum up to 1. Many languages share codes,
+    /// so short texts may be ranked wrong.
+    ///
+    /// Returns error only if binary is malformed.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Language, Morse};
+    ///
+    /// let text = "мы получили ваше сообщение и ответим завтра утром после совещания";
+    /// let bin = Morse::from_text_in(Language::Russian, text).to_bin_str();
+    /// let candidates = Morse::detect_language(&bin).unwrap();
+    ///
+    /// assert_eq!(candidates[0].key, Language::Russian.key());
+    /// assert_eq!(candidates[0].text, text);
+    /// ```
+    pub fn detect_language(bin: &str) -> Result<Vec<LanguageCandidate>, MorseError> {
+        Morse::detect_language_in(&Registry::entries(), bin)
+    }
+
+    /// Decode binary with the alphabets and rank them by confidence,
+    /// same as [Morse::detect_language] does with all registered alphabets.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Language, Morse, RegistryEntry};
+    ///
+    /// let entries = [Language::Russian, Language::Greek].map(RegistryEntry::from);
+    /// let bin = Morse::from_text_in(Language::Greek, "καλημερα").to_bin_str();
+    /// let candidates = Morse::detect_language_in(&entries, &bin).unwrap();
+    ///
+    /// assert_eq!(candidates.len(), 2);
+    /// assert_eq!(candidates[0].key, "el");
+    /// ```
+    pub fn detect_language_in(
+        entries: &[RegistryEntry],
+        bin: &str,
+    ) -> Result<Vec<LanguageCandidate>, MorseError> {
+        let groups = split_bin(bin)?;
+
+        let mut candidates: Vec<(LanguageCandidate, f32)> = entries
+            .iter()
+            .map(|entry| candidate(entry, &groups))
+            .collect();
+
+        // Likelihoods of long texts are far below f32 range, so they are
+        // compared with the best one before leaving logarithms
+        let best = candidates
+            .iter()
+            .map(|(_, likelihood)| *likelihood)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let total: f32 = candidates
+            .iter()
+            .map(|(_, likelihood)| (likelihood - best).exp())
+            .sum();
+        for (candidate, likelihood) in &mut candidates {
+            candidate.confidence = (*likelihood - best).exp() / total;
+        }
+
+        let mut candidates: Vec<LanguageCandidate> = candidates
+            .into_iter()
+            .map(|(candidate, _)| candidate)
+            .collect();
+        candidates.sort_by(|candidate, other| {
+            other
+                .confidence
+                .partial_cmp(&candidate.confidence)
+                .unwrap_or(Ordering::Equal)
+        });
+
+        Ok(candidates)
+    }
+}
+
+// Returns candidate without confidence and logarithm of probability of the codes
+fn candidate(entry: &RegistryEntry, groups: &[(usize, &str)]) -> (LanguageCandidate, f32) {
+    let alphabet = &entry.alphabet;
+    let frequent: Vec<char> = Language::from_key(&entry.key)
+        .map_or("", |language| language.frequent_letters())
+        .chars()
+        .collect();
+
+    // Letter frequencies follow Zipf's law, letters out of the list are rare
+    let weight = |letter: char| match frequent.iter().position(|other| *other == letter) {
+        Some(rank) => 1.0 / (rank as f32 + 2.0),
+        None => 1.0 / (frequent.len() as f32 * 2.0 + 2.0),
+    };
+    let letters: Vec<char> = alphabet
+        .symbols()
+        .into_iter()
+        .filter(|letter| letter.is_alphabetic())
+        .collect();
+    let total: f32 = letters.iter().map(|letter| weight(*letter)).sum();
+    // Alphabet without the list has every letter as probable as any code
+    // of the same units and length, so small alphabets don't take every capture
+    let code_space = {
+        let codes: Vec<Vec<MorseUnit>> = letters
+            .iter()
+            .filter_map(|letter| alphabet.encode(*letter))
+            .collect();
+        let mut units: Vec<MorseUnit> = codes.iter().flatten().copied().collect();
+        units.sort_by_key(|unit| *unit as u8);
+        units.dedup();
+        let longest = codes.iter().map(Vec::len).max().unwrap_or(0);
+
+        (1..=longest as i32)
+            .map(|len| (units.len() as f32).powi(len))
+            .sum::<f32>()
+    };
+    let letter_probability = |letter: char| {
+        if frequent.is_empty() {
+            1.0 / code_space
+        } else {
+            weight(letter) / total
+        }
+    };
+
+    let mut text = String::new();
+    let mut likelihood = match Language::from_key(&entry.key) {
+        Some(Language::International) => INTERNATIONAL_PRIOR.ln(),
+        _ => 0.0,
+    };
+
+    for (_, group) in groups {
+        let m_char = MorseChar::from_bin(group, &entry.key, alphabet);
+        let symbol = match &m_char {
+            Ok(m_char) => m_char.get_symbol().to_string(),
+            Err(_) => '\u{FFFD}'.to_string(),
+        };
+        text.push_str(&symbol);
+
+        if *group == "0" {
+            continue;
+        }
+
+        let probability = match m_char.as_ref().map(|m_char| m_char.get_symbol()) {
+            Ok(Symbol::Letter(letter)) if letter.is_alphabetic() => letter_probability(*letter),
+            Ok(_) => OTHER_SYMBOL,
+            Err(_) => UNKNOWN_CODE,
+        };
+        likelihood += probability.ln();
+    }
+
+    let candidate = LanguageCandidate {
+        key: entry.key.clone(),
+        name: entry.name.clone(),
+        text: alphabet.compose(&text),
+        confidence: 0.0,
+    };
+
+    (candidate, likelihood)
+}
+
+#[cfg(test)]
+mod language_candidate_tests {
+    use super::*;
+    use crate::MorseTable;
+
+    // Tests register own alphabets in parallel, so detection is checked
+    // against built-in languages only
+    fn built_in() -> Vec<RegistryEntry> {
+        Language::ALL
+            .iter()
+            .map(|language| RegistryEntry::from(*language))
+            .collect()
+    }
+
+    #[test]
+    fn detect_language() {
+        let texts = [
+            (
+                Language::International,
+                "the ship will arrive at the harbor this night with the mail",
+            ),
+            (
+                Language::Ukrainian,
+                "ми отримали ваше повідомлення і відповімо завтра вранці",
+            ),
+            (
+                Language::Greek,
+                "η γρηγορη καφε αλεπου πηδαει πανω απο τον τεμπελη σκυλο",
+            ),
+            (
+                Language::German,
+                "der schnelle braune fuchs springt über den faulen hund",
+            ),
+        ];
+
+        for (language, text) in texts {
+            let bin = Morse::from_text_in(language, text).to_bin_str();
+            let candidates = Morse::detect_language_in(&built_in(), &bin).unwrap();
+
+            assert_eq!(candidates[0].key, language.key());
+            assert_eq!(candidates[0].text, text);
+            assert_eq!(candidates.len(), Language::ALL.len());
+            assert!(candidates
+                .windows(2)
+                .all(|pair| pair[0].confidence >= pair[1].confidence));
+        }
+    }
+
+    #[test]
+    fn unknown_codes_lower_confidence() {
+        // Code of Cyrillic Ш is unknown in Latin tables
+        let bin = Morse::from_text_in(Language::Russian, "шшш").to_bin_str();
+        let candidates = Morse::detect_language_in(&built_in(), &bin).unwrap();
+        let international = candidates
+            .iter()
+            .find(|candidate| candidate.key == "en")
+            .unwrap();
+
+        assert_eq!(international.text, "\u{FFFD}\u{FFFD}\u{FFFD}");
+        assert!(international.confidence < 1e-3);
+        assert!(candidates[0].confidence > 0.1);
+        assert_eq!(
+            Morse::detect_language("1012"),
+            Err(MorseError::InvalidBinary {
+                group: "2".to_string(),
+                position: 3
+            })
+        );
+    }
+
+    #[test]
+    fn latin_word_is_international() {
+        for text in ["hello", "test", "the cat"] {
+            let bin = Morse::from_int_text(text).to_bin_str();
+            let candidates = Morse::detect_language_in(&built_in(), &bin).unwrap();
+
+            assert_eq!(candidates[0].key, "en");
+            assert_eq!(candidates[0].name, "International");
+            assert_eq!(candidates[0].text, text);
+            let total: f32 = candidates
+                .iter()
+                .map(|candidate| candidate.confidence)
+                .sum();
+            assert!((total - 1.0).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn small_alphabet_does_not_take_every_capture() {
+        let table = MorseTable::new([
+            ('a', vec![MorseUnit::Dot]),
+            ('b', vec![MorseUnit::Line]),
+            ('c', vec![MorseUnit::Dot; 2]),
+            ('d', vec![MorseUnit::Dot; 3]),
+            ('f', vec![MorseUnit::Dot; 4]),
+            (' ', vec![MorseUnit::Whitespace]),
+        ])
+        .unwrap();
+        let mut entries = built_in();
+        entries.push(RegistryEntry {
+            key: "x-small".to_string(),
+            name: "Small".to_string(),
+            alphabet: table.into(),
+        });
+
+        for text in ["sheet", "the set", "his tie"] {
+            let bin = Morse::from_int_text(text).to_bin_str();
+            let candidates = Morse::detect_language_in(&entries, &bin).unwrap();
+
+            assert_eq!(candidates[0].key, "en");
+            assert!(candidates
+                .iter()
+                .any(|candidate| candidate.key == "x-small" && candidate.text != text));
+        }
+    }
+
+    #[test]
+    fn registered_alphabets() {
+        // Seven lines are unknown in every other alphabet, so other tests are not affected
+        let table = MorseTable::new([
+            ('ꙮ', vec![MorseUnit::Line; 7]),
+            (' ', vec![MorseUnit::Whitespace]),
+        ])
+        .unwrap();
+        Registry::register("x-detect", "Detect", table).unwrap();
+
+        let bin = Morse::new("x-detect").with_text("ꙮꙮ ꙮ").to_bin_str();
+        let candidates = Morse::detect_language(&bin).unwrap();
+
+        assert_eq!(candidates[0].key, "x-detect");
+        assert_eq!(candidates[0].text, "ꙮꙮ ꙮ");
+    }
+}
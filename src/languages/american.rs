@@ -0,0 +1,46 @@
+use crate::MorseUnit::{self, Dot, ExtraLongLine, Gap, Line, LongLine};
+
+/// Letters, digits and punctuation of American (Railroad) Morse Code.
+pub const AMERICAN: &[(char, &[MorseUnit])] = &[
+    ('a', &[Dot, Line]),
+    ('b', &[Line, Dot, Dot, Dot]),
+    ('c', &[Dot, Dot, Gap, Dot]),
+    ('d', &[Line, Dot, Dot]),
+    ('e', &[Dot]),
+    ('f', &[Dot, Line, Dot]),
+    ('g', &[Line, Line, Dot]),
+    ('h', &[Dot, Dot, Dot, Dot]),
+    ('i', &[Dot, Dot]),
+    ('j', &[Line, Dot, Line, Dot]),
+    ('k', &[Line, Dot, Line]),
+    ('l', &[LongLine]),
+    ('m', &[Line, Line]),
+    ('n', &[Line, Dot]),
+    ('o', &[Dot, Gap, Dot]),
+    ('p', &[Dot, Dot, Dot, Dot, Dot]),
+    ('q', &[Dot, Dot, Line, Dot]),
+    ('r', &[Dot, Gap, Dot, Dot]),
+    ('s', &[Dot, Dot, Dot]),
+    ('t', &[Line]),
+    ('u', &[Dot, Dot, Line]),
+    ('v', &[Dot, Dot, Dot, Line]),
+    ('w', &[Dot, Line, Line]),
+    ('x', &[Dot, Line, Dot, Dot]),
+    ('y', &[Dot, Dot, Gap, Dot, Dot]),
+    ('z', &[Dot, Dot, Dot, Gap, Dot]),
+    ('1', &[Dot, Line, Line, Dot]),
+    ('2', &[Dot, Dot, Line, Dot, Dot]),
+    ('3', &[Dot, Dot, Dot, Line, Dot]),
+    ('4', &[Dot, Dot, Dot, Dot, Line]),
+    ('5', &[Line, Line, Line]),
+    ('6', &[Dot, Dot, Dot, Dot, Dot, Dot]),
+    ('7', &[Line, Line, Dot, Dot]),
+    ('8', &[Line, Dot, Dot, Dot, Dot]),
+    ('9', &[Line, Dot, Dot, Line]),
+    ('0', &[ExtraLongLine]),
+    ('.', &[Dot, Dot, Line, Line, Dot, Dot]),
+    (',', &[Dot, Line, Dot, Line]),
+    ('?', &[Line, Dot, Dot, Line, Dot]),
+    ('!', &[Line, Line, Line, Dot]),
+    ('&', &[Dot, Gap, Dot, Dot, Dot]),
+];
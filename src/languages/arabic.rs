@@ -0,0 +1,56 @@
+use crate::MorseUnit::{self, Dot, Line};
+
+/// Arabic letters.
+pub const ARABIC: &[(char, &[MorseUnit])] = &[
+    ('ا', &[Dot, Line]),
+    ('ب', &[Line, Dot, Dot, Dot]),
+    ('ت', &[Line]),
+    ('ث', &[Line, Dot, Line, Dot]),
+    ('ج', &[Dot, Line, Line, Line]),
+    ('ح', &[Dot, Dot, Dot, Dot]),
+    ('خ', &[Line, Line, Line]),
+    ('د', &[Line, Dot, Dot]),
+    ('ذ', &[Line, Line, Dot, Dot]),
+    ('ر', &[Dot, Line, Dot]),
+    ('ز', &[Line, Line, Line, Dot]),
+    ('س', &[Dot, Dot, Dot]),
+    ('ش', &[Line, Line, Line, Line]),
+    ('ص', &[Line, Dot, Dot, Line]),
+    ('ض', &[Dot, Dot, Dot, Line]),
+    ('ط', &[Dot, Dot, Line]),
+    ('ظ', &[Line, Dot, Line, Line]),
+    ('ع', &[Dot, Line, Dot, Line]),
+    ('غ', &[Line, Line, Dot]),
+    ('ف', &[Dot, Dot, Line, Dot]),
+    ('ق', &[Line, Line, Dot, Line]),
+    ('ك', &[Line, Dot, Line]),
+    ('ل', &[Dot, Line, Dot, Dot]),
+    ('م', &[Line, Line]),
+    ('ن', &[Line, Dot]),
+    ('ه', &[Dot, Dot, Line, Dot, Dot]),
+    ('و', &[Dot, Line, Line]),
+    ('ي', &[Dot, Dot]),
+    ('ء', &[Dot]),
+];
+
+/// Letter variants, Arabic punctuation and Arabic-Indic digits.
+pub const ARABIC_ALIASES: &[(char, char)] = &[
+    ('أ', 'ا'),
+    ('إ', 'ا'),
+    ('آ', 'ا'),
+    ('ٱ', 'ا'),
+    ('ى', 'ي'),
+    ('ة', 'ه'),
+    ('،', ','),
+    ('؟', '?'),
+    ('٠', '0'),
+    ('١', '1'),
+    ('٢', '2'),
+    ('٣', '3'),
+    ('٤', '4'),
+    ('٥', '5'),
+    ('٦', '6'),
+    ('٧', '7'),
+    ('٨', '8'),
+    ('٩', '9'),
+];
@@ -0,0 +1,110 @@
+use crate::MorseUnit::{self, Dot, Line};
+
+/// Russian letters, Ё is sent as Е.
+pub const RUSSIAN: &[(char, &[MorseUnit])] = &[
+    ('а', &[Dot, Line]),
+    ('б', &[Line, Dot, Dot, Dot]),
+    ('в', &[Dot, Line, Line]),
+    ('г', &[Line, Line, Dot]),
+    ('д', &[Line, Dot, Dot]),
+    ('е', &[Dot]),
+    ('ж', &[Dot, Dot, Dot, Line]),
+    ('з', &[Line, Line, Dot, Dot]),
+    ('и', &[Dot, Dot]),
+    ('й', &[Dot, Line, Line, Line]),
+    ('к', &[Line, Dot, Line]),
+    ('л', &[Dot, Line, Dot, Dot]),
+    ('м', &[Line, Line]),
+    ('н', &[Line, Dot]),
+    ('о', &[Line, Line, Line]),
+    ('п', &[Dot, Line, Line, Dot]),
+    ('р', &[Dot, Line, Dot]),
+    ('с', &[Dot, Dot, Dot]),
+    ('т', &[Line]),
+    ('у', &[Dot, Dot, Line]),
+    ('ф', &[Dot, Dot, Line, Dot]),
+    ('х', &[Dot, Dot, Dot, Dot]),
+    ('ц', &[Line, Dot, Line, Dot]),
+    ('ч', &[Line, Line, Line, Dot]),
+    ('ш', &[Line, Line, Line, Line]),
+    ('щ', &[Line, Line, Dot, Line]),
+    ('ъ', &[Line, Line, Dot, Line, Line]),
+    ('ы', &[Line, Dot, Line, Line]),
+    ('ь', &[Line, Dot, Dot, Line]),
+    ('э', &[Dot, Dot, Line, Dot, Dot]),
+    ('ю', &[Dot, Dot, Line, Line]),
+    ('я', &[Dot, Line, Dot, Line]),
+];
+
+/// Ukrainian letters.
+pub const UKRAINIAN: &[(char, &[MorseUnit])] = &[
+    ('а', &[Dot, Line]),
+    ('б', &[Line, Dot, Dot, Dot]),
+    ('в', &[Dot, Line, Line]),
+    ('г', &[Line, Line, Dot]),
+    ('ґ', &[Line, Line, Dot, Line, Dot]),
+    ('д', &[Line, Dot, Dot]),
+    ('е', &[Dot]),
+    ('є', &[Dot, Dot, Line, Dot, Dot]),
+    ('ж', &[Dot, Dot, Dot, Line]),
+    ('з', &[Line, Line, Dot, Dot]),
+    ('и', &[Line, Dot, Line, Line]),
+    ('і', &[Dot, Dot]),
+    ('ї', &[Dot, Line, Line, Line, Dot]),
+    ('й', &[Dot, Line, Line, Line]),
+    ('к', &[Line, Dot, Line]),
+    ('л', &[Dot, Line, Dot, Dot]),
+    ('м', &[Line, Line]),
+    ('н', &[Line, Dot]),
+    ('о', &[Line, Line, Line]),
+    ('п', &[Dot, Line, Line, Dot]),
+    ('р', &[Dot, Line, Dot]),
+    ('с', &[Dot, Dot, Dot]),
+    ('т', &[Line]),
+    ('у', &[Dot, Dot, Line]),
+    ('ф', &[Dot, Dot, Line, Dot]),
+    ('х', &[Dot, Dot, Dot, Dot]),
+    ('ц', &[Line, Dot, Line, Dot]),
+    ('ч', &[Line, Line, Line, Dot]),
+    ('ш', &[Line, Line, Line, Line]),
+    ('щ', &[Line, Line, Dot, Line]),
+    ('ь', &[Line, Dot, Dot, Line]),
+    ('ю', &[Dot, Dot, Line, Line]),
+    ('я', &[Dot, Line, Dot, Line]),
+];
+
+/// Belarusian letters, Ё is sent as Е.
+pub const BELARUSIAN: &[(char, &[MorseUnit])] = &[
+    ('а', &[Dot, Line]),
+    ('б', &[Line, Dot, Dot, Dot]),
+    ('в', &[Dot, Line, Line]),
+    ('г', &[Line, Line, Dot]),
+    ('д', &[Line, Dot, Dot]),
+    ('е', &[Dot]),
+    ('ж', &[Dot, Dot, Dot, Line]),
+    ('з', &[Line, Line, Dot, Dot]),
+    ('і', &[Dot, Dot]),
+    ('й', &[Dot, Line, Line, Line]),
+    ('к', &[Line, Dot, Line]),
+    ('л', &[Dot, Line, Dot, Dot]),
+    ('м', &[Line, Line]),
+    ('н', &[Line, Dot]),
+    ('о', &[Line, Line, Line]),
+    ('п', &[Dot, Line, Line, Dot]),
+    ('р', &[Dot, Line, Dot]),
+    ('с', &[Dot, Dot, Dot]),
+    ('т', &[Line]),
+    ('у', &[Dot, Dot, Line]),
+    // Belarusian has no Щ, so Ў takes its code
+    ('ў', &[Line, Line, Dot, Line]),
+    ('ф', &[Dot, Dot, Line, Dot]),
+    ('х', &[Dot, Dot, Dot, Dot]),
+    ('ц', &[Line, Dot, Line, Dot]),
+    ('ч', &[Line, Line, Line, Dot]),
+    ('ш', &[Line, Line, Line, Line]),
+    ('ы', &[Line, Dot, Line, Line]),
+    ('ь', &[Line, Dot, Dot, Line]),
+    ('э', &[Dot, Dot, Line, Dot, Dot]),
+    ('ю', &[Dot, Dot, Line, Line]),
+    ('я', &[Dot, Line, Dot, Line]),
+];
@@ -0,0 +1,53 @@
+use super::final_forms;
+use crate::MorseUnit::{self, Dot, Line};
+
+/// Greek letters.
+pub const GREEK: &[(char, &[MorseUnit])] = &[
+    ('α', &[Dot, Line]),
+    ('β', &[Line, Dot, Dot, Dot]),
+    ('γ', &[Line, Line, Dot]),
+    ('δ', &[Line, Dot, Dot]),
+    ('ε', &[Dot]),
+    ('ζ', &[Line, Line, Dot, Dot]),
+    ('η', &[Dot, Dot, Dot, Dot]),
+    ('θ', &[Line, Dot, Line, Dot]),
+    ('ι', &[Dot, Dot]),
+    ('κ', &[Line, Dot, Line]),
+    ('λ', &[Dot, Line, Dot, Dot]),
+    ('μ', &[Line, Line]),
+    ('ν', &[Line, Dot]),
+    ('ξ', &[Line, Dot, Dot, Line]),
+    ('ο', &[Line, Line, Line]),
+    ('π', &[Dot, Line, Line, Dot]),
+    ('ρ', &[Dot, Line, Dot]),
+    ('σ', &[Dot, Dot, Dot]),
+    ('τ', &[Line]),
+    ('υ', &[Line, Dot, Line, Line]),
+    ('φ', &[Dot, Dot, Line, Dot]),
+    ('χ', &[Line, Line, Line, Line]),
+    ('ψ', &[Line, Line, Dot, Line]),
+    ('ω', &[Dot, Line, Line]),
+];
+
+/// Final sigma and letters with tonos or dialytika are sent as base letters.
+pub const GREEK_ALIASES: &[(char, char)] = &[
+    ('ς', 'σ'),
+    ('ά', 'α'),
+    ('έ', 'ε'),
+    ('ή', 'η'),
+    ('ί', 'ι'),
+    ('ϊ', 'ι'),
+    ('ΐ', 'ι'),
+    ('ό', 'ο'),
+    ('ύ', 'υ'),
+    ('ϋ', 'υ'),
+    ('ΰ', 'υ'),
+    ('ώ', 'ω'),
+    // Greek question mark
+    ('\u{037E}', '?'),
+];
+
+/// Restore final sigma at the end of words.
+pub fn compose_greek(text: &str) -> String {
+    final_forms(text, |letter| (letter == 'σ').then_some('ς'))
+}
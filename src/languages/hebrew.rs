@@ -0,0 +1,42 @@
+use super::final_forms;
+use crate::MorseUnit::{self, Dot, Line};
+
+/// Hebrew letters, final forms are sent as regular ones.
+pub const HEBREW: &[(char, &[MorseUnit])] = &[
+    ('א', &[Dot, Line]),
+    ('ב', &[Line, Dot, Dot, Dot]),
+    ('ג', &[Line, Line, Dot]),
+    ('ד', &[Line, Dot, Dot]),
+    ('ה', &[Line, Line, Line]),
+    ('ו', &[Dot]),
+    ('ז', &[Line, Line, Dot, Dot]),
+    ('ח', &[Dot, Dot, Dot, Dot]),
+    ('ט', &[Dot, Dot, Line]),
+    ('י', &[Dot, Dot]),
+    ('כ', &[Line, Dot, Line]),
+    ('ל', &[Dot, Line, Dot, Dot]),
+    ('מ', &[Line, Line]),
+    ('נ', &[Line, Dot]),
+    ('ס', &[Line, Dot, Line, Dot]),
+    ('ע', &[Dot, Line, Line, Line]),
+    ('פ', &[Dot, Line, Line, Dot]),
+    ('צ', &[Dot, Line, Line]),
+    ('ק', &[Line, Line, Dot, Line]),
+    ('ר', &[Dot, Line, Dot]),
+    ('ש', &[Dot, Dot, Dot]),
+    ('ת', &[Line]),
+];
+
+/// Final forms of letters.
+pub const HEBREW_ALIASES: &[(char, char)] =
+    &[('ך', 'כ'), ('ם', 'מ'), ('ן', 'נ'), ('ף', 'פ'), ('ץ', 'צ')];
+
+/// Restore final forms of letters at the end of words.
+pub fn compose_hebrew(text: &str) -> String {
+    final_forms(text, |letter| {
+        HEBREW_ALIASES
+            .iter()
+            .find(|(_, regular)| *regular == letter)
+            .map(|(final_form, _)| *final_form)
+    })
+}
@@ -0,0 +1,65 @@
+use crate::MorseUnit::{self, Dot, Line};
+
+/// Latin letters of International Morse Code.
+pub const LATIN: &[(char, &[MorseUnit])] = &[
+    ('a', &[Dot, Line]),
+    ('b', &[Line, Dot, Dot, Dot]),
+    ('c', &[Line, Dot, Line, Dot]),
+    ('d', &[Line, Dot, Dot]),
+    ('e', &[Dot]),
+    ('f', &[Dot, Dot, Line, Dot]),
+    ('g', &[Line, Line, Dot]),
+    ('h', &[Dot, Dot, Dot, Dot]),
+    ('i', &[Dot, Dot]),
+    ('j', &[Dot, Line, Line, Line]),
+    ('k', &[Line, Dot, Line]),
+    ('l', &[Dot, Line, Dot, Dot]),
+    ('m', &[Line, Line]),
+    ('n', &[Line, Dot]),
+    ('o', &[Line, Line, Line]),
+    ('p', &[Dot, Line, Line, Dot]),
+    ('q', &[Line, Line, Dot, Line]),
+    ('r', &[Dot, Line, Dot]),
+    ('s', &[Dot, Dot, Dot]),
+    ('t', &[Line]),
+    ('u', &[Dot, Dot, Line]),
+    ('v', &[Dot, Dot, Dot, Line]),
+    ('w', &[Dot, Line, Line]),
+    ('x', &[Line, Dot, Dot, Line]),
+    ('y', &[Line, Dot, Line, Line]),
+    ('z', &[Line, Line, Dot, Dot]),
+];
+
+/// Digits, shared by most of the alphabets.
+pub const DIGITS: &[(char, &[MorseUnit])] = &[
+    ('1', &[Dot, Line, Line, Line, Line]),
+    ('2', &[Dot, Dot, Line, Line, Line]),
+    ('3', &[Dot, Dot, Dot, Line, Line]),
+    ('4', &[Dot, Dot, Dot, Dot, Line]),
+    ('5', &[Dot, Dot, Dot, Dot, Dot]),
+    ('6', &[Line, Dot, Dot, Dot, Dot]),
+    ('7', &[Line, Line, Dot, Dot, Dot]),
+    ('8', &[Line, Line, Line, Dot, Dot]),
+    ('9', &[Line, Line, Line, Line, Dot]),
+    ('0', &[Line, Line, Line, Line, Line]),
+];
+
+/// ITU-R M.1677-1 punctuation marks and miscellaneous signs.
+pub const PUNCTUATION: &[(char, &[MorseUnit])] = &[
+    ('.', &[Dot, Line, Dot, Line, Dot, Line]),
+    (',', &[Line, Line, Dot, Dot, Line, Line]),
+    (':', &[Line, Line, Line, Dot, Dot, Dot]),
+    ('?', &[Dot, Dot, Line, Line, Dot, Dot]),
+    ('\'', &[Dot, Line, Line, Line, Line, Dot]),
+    ('-', &[Line, Dot, Dot, Dot, Dot, Line]),
+    ('/', &[Line, Dot, Dot, Line, Dot]),
+    ('(', &[Line, Dot, Line, Line, Dot]),
+    (')', &[Line, Dot, Line, Line, Dot, Line]),
+    ('"', &[Dot, Line, Dot, Dot, Line, Dot]),
+    ('=', &[Line, Dot, Dot, Dot, Line]),
+    ('+', &[Dot, Line, Dot, Line, Dot]),
+    ('@', &[Dot, Line, Line, Dot, Line, Dot]),
+];
+
+/// Accented letter of International Morse Code.
+pub const ACCENTED: &[(char, &[MorseUnit])] = &[('é', &[Dot, Dot, Line, Dot, Dot])];
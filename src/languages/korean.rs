@@ -0,0 +1,219 @@
+use crate::MorseUnit::{self, Dot, Line};
+
+/// Korean jamo of SKATS, Standard Korean Alphabet Transliteration System.
+pub const KOREAN: &[(char, &[MorseUnit])] = &[
+    ('ㄱ', &[Dot, Line, Dot, Dot]),
+    ('ㄴ', &[Dot, Dot, Line, Dot]),
+    ('ㄷ', &[Line, Dot, Dot, Dot]),
+    ('ㄹ', &[Dot, Dot, Dot, Line]),
+    ('ㅁ', &[Line, Line]),
+    ('ㅂ', &[Dot, Line, Line]),
+    ('ㅅ', &[Line, Line, Dot]),
+    ('ㅇ', &[Line, Dot, Line]),
+    ('ㅈ', &[Dot, Line, Line, Dot]),
+    ('ㅊ', &[Line, Dot, Line, Dot]),
+    ('ㅋ', &[Line, Dot, Dot, Line]),
+    ('ㅌ', &[Line, Line, Dot, Dot]),
+    ('ㅍ', &[Line, Line, Line]),
+    ('ㅎ', &[Dot, Line, Line, Line]),
+    ('ㅏ', &[Dot]),
+    ('ㅑ', &[Dot, Dot]),
+    ('ㅓ', &[Line]),
+    ('ㅕ', &[Dot, Dot, Dot]),
+    ('ㅗ', &[Dot, Line]),
+    ('ㅛ', &[Line, Dot]),
+    ('ㅜ', &[Dot, Dot, Dot, Dot]),
+    ('ㅠ', &[Dot, Line, Dot]),
+    ('ㅡ', &[Line, Dot, Dot]),
+    ('ㅣ', &[Dot, Dot, Line]),
+    ('ㅐ', &[Line, Line, Dot, Line]),
+    ('ㅔ', &[Line, Dot, Line, Line]),
+];
+
+const FIRST_SYLLABLE: u32 = 0xAC00;
+const LAST_SYLLABLE: u32 = 0xD7A3;
+const FIRST_VOWEL: u32 = 'ㅏ' as u32;
+const VOWELS: u32 = 21;
+const FINALS_PER_VOWEL: u32 = 28;
+
+const INITIALS: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+const FINALS: [char; 27] = [
+    'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ',
+    'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+// Double consonants, consonant clusters and compound vowels have no own code
+const COMPOUND_JAMO: [(char, [char; 2]); 25] = [
+    ('ㄲ', ['ㄱ', 'ㄱ']),
+    ('ㄳ', ['ㄱ', 'ㅅ']),
+    ('ㄵ', ['ㄴ', 'ㅈ']),
+    ('ㄶ', ['ㄴ', 'ㅎ']),
+    ('ㄸ', ['ㄷ', 'ㄷ']),
+    ('ㄺ', ['ㄹ', 'ㄱ']),
+    ('ㄻ', ['ㄹ', 'ㅁ']),
+    ('ㄼ', ['ㄹ', 'ㅂ']),
+    ('ㄽ', ['ㄹ', 'ㅅ']),
+    ('ㄾ', ['ㄹ', 'ㅌ']),
+    ('ㄿ', ['ㄹ', 'ㅍ']),
+    ('ㅀ', ['ㄹ', 'ㅎ']),
+    ('ㅃ', ['ㅂ', 'ㅂ']),
+    ('ㅄ', ['ㅂ', 'ㅅ']),
+    ('ㅆ', ['ㅅ', 'ㅅ']),
+    ('ㅉ', ['ㅈ', 'ㅈ']),
+    ('ㅒ', ['ㅑ', 'ㅣ']),
+    ('ㅖ', ['ㅕ', 'ㅣ']),
+    ('ㅘ', ['ㅗ', 'ㅏ']),
+    ('ㅙ', ['ㅗ', 'ㅐ']),
+    ('ㅚ', ['ㅗ', 'ㅣ']),
+    ('ㅝ', ['ㅜ', 'ㅓ']),
+    ('ㅞ', ['ㅜ', 'ㅔ']),
+    ('ㅟ', ['ㅜ', 'ㅣ']),
+    ('ㅢ', ['ㅡ', 'ㅣ']),
+];
+
+/// Split Hangul syllable into jamo of the table, double consonants,
+/// consonant clusters and compound vowels are split too.
+pub fn decompose_hangul(letter: char) -> Option<String> {
+    let code = letter as u32;
+    if !(FIRST_SYLLABLE..=LAST_SYLLABLE).contains(&code) {
+        return split_jamo(letter).map(|parts| parts.iter().collect());
+    }
+
+    let index = code - FIRST_SYLLABLE;
+    let initial = INITIALS[(index / (VOWELS * FINALS_PER_VOWEL)) as usize];
+    let vowel =
+        char::from_u32(FIRST_VOWEL + index % (VOWELS * FINALS_PER_VOWEL) / FINALS_PER_VOWEL)?;
+    let last = (index % FINALS_PER_VOWEL) as usize;
+    let last = last.checked_sub(1).map(|last| FINALS[last]);
+
+    let mut jamo = String::new();
+    for letter in [Some(initial), Some(vowel), last].into_iter().flatten() {
+        match split_jamo(letter) {
+            Some(parts) => jamo.extend(parts),
+            None => jamo.push(letter),
+        }
+    }
+
+    Some(jamo)
+}
+
+/// Join jamo into Hangul syllables. Jamo that don't form a syllable are kept as is.
+pub fn compose_hangul(text: &str) -> String {
+    let letters: Vec<char> = text.chars().collect();
+    let mut composed = String::new();
+    let mut idx = 0;
+
+    while idx < letters.len() {
+        match syllable_at(&letters[idx..]) {
+            Some((syllable, len)) => {
+                composed.push(syllable);
+                idx += len;
+            }
+            None => {
+                composed.push(letters[idx]);
+                idx += 1;
+            }
+        }
+    }
+
+    composed
+}
+
+// Syllable is initial consonant, vowel and optional final consonant.
+// Consonant before vowel starts the next syllable. Doubled consonant before vowel
+// is read the way it is usually written: ㄸ, ㅃ and ㅉ are never final, so
+// 아빠, but ㄱㄱ and ㅅㅅ are final and initial, so 각가, unless final is already taken
+fn syllable_at(letters: &[char]) -> Option<(char, usize)> {
+    let initials = count_consonants(letters);
+    let initial = match initials {
+        1 => letters[0],
+        2 => join_jamo(letters[0], letters[1]).filter(|initial| INITIALS.contains(initial))?,
+        _ => return None,
+    };
+
+    let mut len = initials;
+    let vowel = *letters.get(len).filter(|vowel| is_vowel(**vowel))?;
+    let vowel = match letters
+        .get(len + 1)
+        .and_then(|next| join_jamo(vowel, *next))
+    {
+        Some(compound) => {
+            len += 2;
+            compound
+        }
+        None => {
+            len += 1;
+            vowel
+        }
+    };
+
+    let consonants = count_consonants(&letters[len..]);
+    let next_initials = match letters.get(len + consonants) {
+        Some(next) if is_vowel(*next) && consonants > 0 => {
+            let pair = &letters[len + consonants - 2.min(consonants)..len + consonants];
+            let doubled = pair.len() == 2
+                && pair[0] == pair[1]
+                && join_jamo(pair[0], pair[1]).is_some_and(|double| INITIALS.contains(&double));
+            if doubled && (consonants > 2 || matches!(pair[0], 'ㄷ' | 'ㅂ' | 'ㅈ')) {
+                2
+            } else {
+                1
+            }
+        }
+        _ => 0,
+    };
+
+    let finals = &letters[len..len + consonants - next_initials];
+    let last = match finals {
+        [] => None,
+        [last] => Some((*last, 1)),
+        [first, second, ..] => {
+            match join_jamo(*first, *second).filter(|last| FINALS.contains(last)) {
+                Some(last) => Some((last, 2)),
+                None => Some((*first, 1)),
+            }
+        }
+    };
+    let last_index = match last {
+        Some((last, last_len)) => {
+            len += last_len;
+            FINALS.iter().position(|final_jamo| *final_jamo == last)? as u32 + 1
+        }
+        None => 0,
+    };
+
+    let initial_index = INITIALS.iter().position(|jamo| *jamo == initial)? as u32;
+    let vowel_index = vowel as u32 - FIRST_VOWEL;
+    let syllable = char::from_u32(
+        FIRST_SYLLABLE + (initial_index * VOWELS + vowel_index) * FINALS_PER_VOWEL + last_index,
+    )?;
+
+    Some((syllable, len))
+}
+
+fn count_consonants(letters: &[char]) -> usize {
+    letters
+        .iter()
+        .take_while(|letter| ('ㄱ'..='ㅎ').contains(*letter))
+        .count()
+}
+
+fn is_vowel(letter: char) -> bool {
+    ('ㅏ'..='ㅣ').contains(&letter)
+}
+
+fn split_jamo(letter: char) -> Option<[char; 2]> {
+    COMPOUND_JAMO
+        .iter()
+        .find(|(compound, _)| *compound == letter)
+        .map(|(_, parts)| *parts)
+}
+
+fn join_jamo(first: char, second: char) -> Option<char> {
+    COMPOUND_JAMO
+        .iter()
+        .find(|(_, parts)| *parts == [first, second])
+        .map(|(compound, _)| *compound)
+}
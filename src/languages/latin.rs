@@ -0,0 +1,87 @@
+use crate::MorseUnit::{self, Dot, Line};
+
+/// German umlauts and sharp s.
+pub const GERMAN: &[(char, &[MorseUnit])] = &[
+    ('ä', &[Dot, Line, Dot, Line]),
+    ('ö', &[Line, Line, Line, Dot]),
+    ('ü', &[Dot, Dot, Line, Line]),
+    ('ß', &[Dot, Dot, Dot, Line, Line, Dot, Dot]),
+];
+
+/// Spanish letters.
+pub const SPANISH: &[(char, &[MorseUnit])] = &[
+    ('ñ', &[Line, Line, Dot, Line, Line]),
+    ('ü', &[Dot, Dot, Line, Line]),
+];
+
+/// Danish and Norwegian letters.
+pub const DANO_NORWEGIAN: &[(char, &[MorseUnit])] = &[
+    ('æ', &[Dot, Line, Dot, Line]),
+    ('ø', &[Line, Line, Line, Dot]),
+    ('å', &[Dot, Line, Line, Dot, Line]),
+];
+
+/// Swedish and Finnish letters.
+pub const SWEDISH: &[(char, &[MorseUnit])] = &[
+    ('ä', &[Dot, Line, Dot, Line]),
+    ('ö', &[Line, Line, Line, Dot]),
+    ('å', &[Dot, Line, Line, Dot, Line]),
+];
+
+/// Polish letters.
+pub const POLISH: &[(char, &[MorseUnit])] = &[
+    ('ą', &[Dot, Line, Dot, Line]),
+    ('ć', &[Line, Dot, Line, Dot, Dot]),
+    ('ę', &[Dot, Dot, Line, Dot, Dot]),
+    ('ł', &[Dot, Line, Dot, Dot, Line]),
+    ('ń', &[Line, Line, Dot, Line, Line]),
+    ('ó', &[Line, Line, Line, Dot]),
+    ('ś', &[Dot, Dot, Dot, Line, Dot, Dot, Dot]),
+    ('ź', &[Line, Line, Dot, Dot, Line, Dot]),
+    ('ż', &[Line, Line, Dot, Dot, Line]),
+];
+
+/// Turkish letters.
+pub const TURKISH: &[(char, &[MorseUnit])] = &[
+    ('ç', &[Line, Dot, Line, Dot, Dot]),
+    ('ğ', &[Line, Line, Dot, Line, Dot]),
+    ('ö', &[Line, Line, Line, Dot]),
+    ('ş', &[Dot, Line, Line, Dot, Dot]),
+    ('ü', &[Dot, Dot, Line, Line]),
+];
+
+/// Replace accented Latin letter by base letter, when the language
+/// has no code for it. Case of the letter is kept.
+pub fn transliterate_latin(letter: char) -> Option<String> {
+    let mut lowercase = letter.to_lowercase();
+    let base = match lowercase.next()? {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'ĉ' | 'č' => "c",
+        'ď' | 'đ' | 'ð' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ĝ' | 'ğ' => "g",
+        'ĥ' => "h",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' | 'i' => "i",
+        'ĵ' => "j",
+        'ł' | 'ľ' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ŕ' | 'ř' => "r",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'ß' => "ss",
+        'ť' | 'ţ' => "t",
+        'þ' => "th",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' | 'ų' | 'ŭ' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+
+    if letter.is_uppercase() {
+        Some(base.to_uppercase())
+    } else {
+        Some(base.to_string())
+    }
+}
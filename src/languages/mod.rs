@@ -0,0 +1,347 @@
+//! Built-in languages of Morse Code.
+
+use std::{
+    fmt,
+    sync::{Arc, OnceLock},
+};
+
+use crate::{Alphabet, MorseTable, MorseUnit, Prosign};
+
+mod american;
+use american::*;
+
+mod arabic;
+use arabic::*;
+
+mod cyrillic;
+use cyrillic::*;
+
+mod greek;
+use greek::*;
+
+mod hebrew;
+use hebrew::*;
+
+mod international;
+use international::*;
+
+mod korean;
+use korean::*;
+
+mod latin;
+use latin::*;
+
+mod wabun;
+use wabun::*;
+
+/// ## Built-in languages of Morse Code.
+///
+/// Every language includes International digits and ITU-R M.1677-1 punctuation marks,
+/// except two: Wabun code has the digits, but uses codes of punctuation marks for kana,
+/// and American Morse Code has own digits and punctuation marks.
+/// Languages with Latin letters send accented letters without own code as base letters.
+/// # Examples
+///
+/// ```
+/// use morse_lib::{Language, Morse};
+///
+/// let morse = Morse::from_text_in(Language::Ukrainian, "Ґанок");
+///
+/// assert_eq!(morse.get_language(), "Ukrainian");
+/// assert_eq!(morse.to_string(), "⚊ ⚊ . ⚊ .   . ⚊   ⚊ .   ⚊ ⚊ ⚊   ⚊ . ⚊");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Language {
+    International,
+    Russian,
+    Ukrainian,
+    Belarusian,
+    Greek,
+    Hebrew,
+    Arabic,
+    /// Japanese Wabun code. Katakana and hiragana are both sent as katakana,
+    /// voiced kana are sent as base kana followed by voiced mark.
+    Wabun,
+    /// Korean SKATS. Hangul syllables are sent as jamo and joined back on decoding.
+    Korean,
+    /// International Morse Code with Ä, Ö, Ü, ß and CH.
+    German,
+    /// International Morse Code with Ñ and Ü.
+    Spanish,
+    /// International Morse Code with Æ, Ø and Å.
+    Danish,
+    /// International Morse Code with Æ, Ø and Å.
+    Norwegian,
+    /// International Morse Code with Å, Ä and Ö.
+    Swedish,
+    /// International Morse Code with Å, Ä and Ö.
+    Finnish,
+    /// International Morse Code with Polish letters.
+    Polish,
+    /// International Morse Code with Turkish letters.
+    Turkish,
+    /// American (Railroad) Morse Code with spaces inside letters and long lines.
+    American,
+}
+
+impl Language {
+    /// All built-in languages.
+    pub const ALL: &'static [Language] = &[
+        Language::International,
+        Language::Russian,
+        Language::Ukrainian,
+        Language::Belarusian,
+        Language::Greek,
+        Language::Hebrew,
+        Language::Arabic,
+        Language::Wabun,
+        Language::Korean,
+        Language::German,
+        Language::Spanish,
+        Language::Danish,
+        Language::Norwegian,
+        Language::Swedish,
+        Language::Finnish,
+        Language::Polish,
+        Language::Turkish,
+        Language::American,
+    ];
+
+    /// Return language label.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::International => "International",
+            Language::Russian => "Russian",
+            Language::Ukrainian => "Ukrainian",
+            Language::Belarusian => "Belarusian",
+            Language::Greek => "Greek",
+            Language::Hebrew => "Hebrew",
+            Language::Arabic => "Arabic",
+            Language::Wabun => "Wabun",
+            Language::Korean => "Korean",
+            Language::German => "German",
+            Language::Spanish => "Spanish",
+            Language::Danish => "Danish",
+            Language::Norwegian => "Norwegian",
+            Language::Swedish => "Swedish",
+            Language::Finnish => "Finnish",
+            Language::Polish => "Polish",
+            Language::Turkish => "Turkish",
+            Language::American => "American",
+        }
+    }
+
+    /// Return registry key of the language: ISO 639-1 code, followed by variant
+    /// for codes that are not the main code of the language.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Language::International => "en",
+            Language::Russian => "ru",
+            Language::Ukrainian => "uk",
+            Language::Belarusian => "be",
+            Language::Greek => "el",
+            Language::Hebrew => "he",
+            Language::Arabic => "ar",
+            Language::Wabun => "ja",
+            Language::Korean => "ko",
+            Language::German => "de",
+            Language::Spanish => "es",
+            Language::Danish => "da",
+            Language::Norwegian => "no",
+            Language::Swedish => "sv",
+            Language::Finnish => "fi",
+            Language::Polish => "pl",
+            Language::Turkish => "tr",
+            Language::American => "en-railroad",
+        }
+    }
+
+    /// Return language by its registry key.
+    pub fn from_key(key: &str) -> Option<Language> {
+        Language::ALL
+            .iter()
+            .find(|language| language.key() == key)
+            .copied()
+    }
+
+    /// Return language by its label.
+    pub fn from_name(name: &str) -> Option<Language> {
+        Language::ALL
+            .iter()
+            .find(|language| language.name() == name)
+            .copied()
+    }
+
+    /// Return letters of the language from the most frequent one.
+    pub fn frequent_letters(&self) -> &'static str {
+        match self {
+            Language::International | Language::American => "etaoinshrdlcumwfgypbvkjxqz",
+            Language::Russian => "оеаинтсрвлкмдпуяыьгзбчйхжшюцщэфъ",
+            Language::Ukrainian => "оаніивтерсклудмпяьгзбчхйжюцшщєїфґ",
+            Language::Belarusian => "аоынеірсткдлвмупяьзгбчйхжшўюцэф",
+            Language::Greek => "αοιετσνηυρπκμλωδγχθφβξζψ",
+            Language::Hebrew => "יוהלאמרבתנשכעדקפחסצגטז",
+            Language::Arabic => "اليمونرتبهعدفقسكحجشصطزخضذثغظء",
+            Language::Wabun => "ンイノカトシタテルスニクマコキナアオラハ",
+            Language::Korean => "ㅇㅏㄴㄱㅣㅓㄹㅡㅗㅅㄷㅈㅁㅎㅜㅂㅔㅐㅊㅕㅌㅋㅍㅛㅠㅑ",
+            Language::German => "enisratdhulcgmobwfkzpvüäößjyxq",
+            Language::Spanish => "eaosrnidlctumpbgvyqhfzjñxwk",
+            Language::Danish | Language::Norwegian => "erntiasdlogkmvfubpåhøjyæcwzxq",
+            Language::Swedish => "eantrslidomkgvhfuäpåöbcyjxwzq",
+            Language::Finnish => "aitneslokuämvrjhypdögbfcwxzq",
+            Language::Polish => "aioeznrwstcykdpmuljłbgęhąóżśćfńźvxq",
+            Language::Turkish => "aeinrlıkdmyutsbozüşgçhğvcöpfjw",
+        }
+    }
+
+    /// Return true if the language is written from right to left.
+    ///
+    /// Morse Code is sent in logical order, so decoded text of such language
+    /// is in logical order too and needs no reversing.
+    pub fn is_right_to_left(&self) -> bool {
+        matches!(self, Language::Hebrew | Language::Arabic)
+    }
+
+    /// Return shared table of the language.
+    pub fn table(&self) -> Arc<MorseTable> {
+        static TABLES: [OnceLock<Arc<MorseTable>>; Language::ALL.len()] =
+            [const { OnceLock::new() }; Language::ALL.len()];
+
+        TABLES[*self as usize]
+            .get_or_init(|| Arc::new(self.build_table()))
+            .clone()
+    }
+
+    fn build_table(&self) -> MorseTable {
+        type Pairs = &'static [(char, &'static [MorseUnit])];
+
+        let (parts, aliases): (&[Pairs], &[(char, char)]) = match self {
+            Language::International => (
+                &[LATIN, DIGITS, ACCENTED, PUNCTUATION],
+                // Multiplication sign shares the code with letter X
+                &[('×', 'x')],
+            ),
+            Language::Russian => (&[RUSSIAN, DIGITS, PUNCTUATION], &[('ё', 'е')]),
+            Language::Ukrainian => (&[UKRAINIAN, DIGITS, PUNCTUATION], &[('ʼ', '\'')]),
+            Language::Belarusian => (
+                &[BELARUSIAN, DIGITS, PUNCTUATION],
+                &[('ё', 'е'), ('ʼ', '\'')],
+            ),
+            Language::Greek => (&[GREEK, DIGITS, PUNCTUATION], GREEK_ALIASES),
+            Language::Hebrew => (&[HEBREW, DIGITS, PUNCTUATION], HEBREW_ALIASES),
+            Language::Arabic => (&[ARABIC, DIGITS, PUNCTUATION], ARABIC_ALIASES),
+            Language::Wabun => (&[WABUN, DIGITS], &[]),
+            Language::Korean => (&[KOREAN, DIGITS, PUNCTUATION], &[]),
+            Language::German => (&[LATIN, DIGITS, ACCENTED, GERMAN, PUNCTUATION], &[]),
+            Language::Spanish => (&[LATIN, DIGITS, ACCENTED, SPANISH, PUNCTUATION], &[]),
+            // Ä and Ö share codes with Æ and Ø
+            Language::Danish | Language::Norwegian => (
+                &[LATIN, DIGITS, ACCENTED, DANO_NORWEGIAN, PUNCTUATION],
+                &[('ä', 'æ'), ('ö', 'ø')],
+            ),
+            Language::Swedish | Language::Finnish => (
+                &[LATIN, DIGITS, ACCENTED, SWEDISH, PUNCTUATION],
+                &[('æ', 'ä'), ('ø', 'ö')],
+            ),
+            // Ę takes the code of É
+            Language::Polish => (&[LATIN, DIGITS, POLISH, PUNCTUATION], &[]),
+            Language::Turkish => (&[LATIN, DIGITS, ACCENTED, TURKISH, PUNCTUATION], &[]),
+            Language::American => (&[AMERICAN], &[]),
+        };
+
+        let pairs = parts
+            .iter()
+            .flat_map(|pairs| pairs.iter())
+            .map(|(letter, m_char)| (*letter, m_char.to_vec()))
+            .chain([(' ', vec![MorseUnit::Whitespace])]);
+        let mut table = MorseTable::new(pairs).expect("built-in Morse Code tables are valid");
+        for (alias, letter) in aliases {
+            table.alias(*alias, *letter);
+        }
+
+        // Japanese operators switch with <DO> to Wabun code and with <SN> back
+        match self {
+            Language::International => {
+                table.shift(Prosign::DO, Language::Wabun);
+                table.decompose_with(transliterate_latin);
+            }
+            Language::German => {
+                table.digraph("ch", vec![MorseUnit::Line; 4]);
+                table.decompose_with(transliterate_latin);
+            }
+            Language::Spanish
+            | Language::Danish
+            | Language::Norwegian
+            | Language::Swedish
+            | Language::Finnish
+            | Language::Polish
+            | Language::Turkish
+            | Language::American => table.decompose_with(transliterate_latin),
+            Language::Wabun => {
+                table.shift(Prosign::SN, Language::International);
+                table.decompose_with(decompose_kana);
+                table.compose_with(compose_kana);
+            }
+            Language::Korean => {
+                table.decompose_with(decompose_hangul);
+                table.compose_with(compose_hangul);
+            }
+            Language::Greek => table.compose_with(compose_greek),
+            Language::Hebrew => table.compose_with(compose_hebrew),
+            _ => {}
+        }
+
+        table
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl From<Language> for Alphabet {
+    fn from(language: Language) -> Self {
+        Alphabet::Table(language.table())
+    }
+}
+
+/// Replace letters at the end of words by their final forms.
+/// Single letter is not a word end, e.g. abbreviation.
+fn final_forms(text: &str, final_form: impl Fn(char) -> Option<char>) -> String {
+    let letters: Vec<char> = text.chars().collect();
+
+    letters
+        .iter()
+        .enumerate()
+        .map(|(idx, letter)| {
+            let after_letter = idx > 0 && letters[idx - 1].is_alphabetic();
+            let word_end = letters
+                .get(idx + 1)
+                .is_none_or(|next| !next.is_alphabetic());
+
+            match final_form(*letter) {
+                Some(final_form) if after_letter && word_end => final_form,
+                _ => *letter,
+            }
+        })
+        .collect()
+}
+
+/// Shared table of International Morse Code.
+pub fn international() -> Arc<MorseTable> {
+    Language::International.table()
+}
+
+#[cfg(test)]
+mod languages_tests {
+    use super::*;
+
+    #[test]
+    fn built_in_tables_are_valid() {
+        for language in Language::ALL {
+            language.table();
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::MorseUnit::{self, Dot, Line};
+
+/// Japanese katakana of Wabun code.
+pub const WABUN: &[(char, &[MorseUnit])] = &[
+    ('ア', &[Line, Line, Dot, Line, Line]),
+    ('イ', &[Dot, Line]),
+    ('ウ', &[Dot, Dot, Line]),
+    ('エ', &[Line, Dot, Line, Line, Line]),
+    ('オ', &[Dot, Line, Dot, Dot, Dot]),
+    ('カ', &[Dot, Line, Dot, Dot]),
+    ('キ', &[Line, Dot, Line, Dot, Dot]),
+    ('ク', &[Dot, Dot, Dot, Line]),
+    ('ケ', &[Line, Dot, Line, Line]),
+    ('コ', &[Line, Line, Line, Line]),
+    ('サ', &[Line, Dot, Line, Dot, Line]),
+    ('シ', &[Line, Line, Dot, Line, Dot]),
+    ('ス', &[Line, Line, Line, Dot, Line]),
+    ('セ', &[Dot, Line, Line, Line, Dot]),
+    ('ソ', &[Line, Line, Line, Dot]),
+    ('タ', &[Line, Dot]),
+    ('チ', &[Dot, Dot, Line, Dot]),
+    ('ツ', &[Dot, Line, Line, Dot]),
+    ('テ', &[Dot, Line, Dot, Line, Line]),
+    ('ト', &[Dot, Dot, Line, Dot, Dot]),
+    ('ナ', &[Dot, Line, Dot]),
+    ('ニ', &[Line, Dot, Line, Dot]),
+    ('ヌ', &[Dot, Dot, Dot, Dot]),
+    ('ネ', &[Line, Line, Dot, Line]),
+    ('ノ', &[Dot, Dot, Line, Line]),
+    ('ハ', &[Line, Dot, Dot, Dot]),
+    ('ヒ', &[Line, Line, Dot, Dot, Line]),
+    ('フ', &[Line, Line, Dot, Dot]),
+    ('ヘ', &[Dot]),
+    ('ホ', &[Line, Dot, Dot]),
+    ('マ', &[Line, Dot, Dot, Line]),
+    ('ミ', &[Dot, Dot, Line, Dot, Line]),
+    ('ム', &[Line]),
+    ('メ', &[Line, Dot, Dot, Dot, Line]),
+    ('モ', &[Line, Dot, Dot, Line, Dot]),
+    ('ヤ', &[Dot, Line, Line]),
+    ('ユ', &[Line, Dot, Dot, Line, Line]),
+    ('ヨ', &[Line, Line]),
+    ('ラ', &[Dot, Dot, Dot]),
+    ('リ', &[Line, Line, Dot]),
+    ('ル', &[Line, Dot, Line, Line, Dot]),
+    ('レ', &[Line, Line, Line]),
+    ('ロ', &[Dot, Line, Dot, Line]),
+    ('ワ', &[Line, Dot, Line]),
+    ('ヰ', &[Dot, Line, Dot, Dot, Line]),
+    ('ヱ', &[Dot, Line, Line, Dot, Dot]),
+    ('ヲ', &[Dot, Line, Line, Line]),
+    ('ン', &[Dot, Line, Dot, Line, Dot]),
+    // Voiced and semi-voiced marks are separate signs
+    ('゛', &[Dot, Dot]),
+    ('゜', &[Dot, Dot, Line, Line, Dot]),
+    ('ー', &[Dot, Line, Line, Dot, Line]),
+    ('、', &[Dot, Line, Dot, Line, Dot, Line]),
+    ('」', &[Dot, Line, Dot, Line, Dot, Dot]),
+    ('（', &[Line, Dot, Line, Line, Dot, Line]),
+    ('）', &[Dot, Line, Dot, Dot, Line, Dot]),
+];
+
+const VOICED: &str = "ガギグゲゴザジズゼゾダヂヅデドバビブベボヴ";
+const SEMI_VOICED: &str = "パピプペポ";
+const SMALL: &str = "ァィゥェォッャュョヮヵヶ";
+
+/// Split kana into katakana of the table: hiragana is sent as katakana,
+/// small kana as full-size ones, voiced kana as base kana with voiced mark.
+pub fn decompose_kana(letter: char) -> Option<String> {
+    let katakana = match letter {
+        'ぁ'..='ゖ' => char::from_u32(letter as u32 + 0x60)?,
+        // Combining voiced and semi-voiced marks
+        '\u{3099}' => '゛',
+        '\u{309A}' => '゜',
+        _ => letter,
+    };
+
+    let decomposed = if VOICED.contains(katakana) {
+        format!("{}゛", base_kana(katakana)?)
+    } else if SEMI_VOICED.contains(katakana) {
+        format!("{}゜", base_kana(katakana)?)
+    } else if SMALL.contains(katakana) {
+        match katakana {
+            'ヵ' => "カ".to_string(),
+            'ヶ' => "ケ".to_string(),
+            _ => char::from_u32(katakana as u32 + 1)?.to_string(),
+        }
+    } else {
+        katakana.to_string()
+    };
+
+    (decomposed != letter.to_string()).then_some(decomposed)
+}
+
+/// Join katakana with following voiced or semi-voiced mark.
+pub fn compose_kana(text: &str) -> String {
+    let mut composed = String::new();
+    let mut letters = text.chars().peekable();
+
+    while let Some(letter) = letters.next() {
+        let joined = match letters.peek() {
+            Some('゛') => VOICED
+                .chars()
+                .find(|voiced| base_kana(*voiced) == Some(letter)),
+            Some('゜') => SEMI_VOICED
+                .chars()
+                .find(|voiced| base_kana(*voiced) == Some(letter)),
+            _ => None,
+        };
+
+        match joined {
+            Some(joined) => {
+                composed.push(joined);
+                letters.next();
+            }
+            None => composed.push(letter),
+        }
+    }
+
+    composed
+}
+
+fn base_kana(voiced: char) -> Option<char> {
+    if voiced == 'ヴ' {
+        Some('ウ')
+    } else if VOICED.contains(voiced) {
+        char::from_u32(voiced as u32 - 1)
+    } else if SEMI_VOICED.contains(voiced) {
+        char::from_u32(voiced as u32 - 2)
+    } else {
+        None
+    }
+}
@@ -12,10 +12,18 @@
 //!
 //! ## Extend multimultilingualism
 //!
-//! To provide custom language conversion the library accept two functions:
+//! To provide custom language conversion the library accept [MorseTable] built
+//! from a list of characters with their Morse Code, or two functions:
 //! - first that match conversion from character to Morse Code
 //! - second that match conversion from Morse Code to Character
 //!
+//! Both functions return `None` when conversion is impossible.
+//!
+//! ## Errors
+//!
+//! Every constructor and parser has a `try_` version that returns [MorseError]
+//! instead of panic on unknown characters or malformed binary.
+//!
 //! ## Data formats
 //!
 //! The following is a list of data formats that have been implemented
@@ -33,7 +41,29 @@
 //! - [Binary String], the casual String that contains Morse Code represented by byte code.
 //! - [Sound], sound representation of Morse Code
 
-use std::{cell::RefCell, thread, time};
+use std::{cell::RefCell, fmt, time};
+
+mod alphabet;
+pub use alphabet::Alphabet;
+
+#[cfg(any(feature = "toml", feature = "json"))]
+mod alphabet_file;
+
+mod envelope;
+pub use envelope::{Envelope, EnvelopeShape};
+
+mod error;
+pub use error::MorseError;
+
+mod language_candidate;
+pub use language_candidate::LanguageCandidate;
+
+mod languages;
+use languages::international;
+pub use languages::Language;
+
+mod morse_alphabet;
+pub use morse_alphabet::MorseAlphabet;
 
 mod morse_char;
 use morse_char::*;
@@ -44,11 +74,31 @@ pub use morse_unit::MorseUnit;
 mod morse_processors;
 use morse_processors::*;
 
+mod morse_table;
+pub use morse_table::MorseTable;
+
+mod morse_trie;
+pub use morse_trie::{MorseTrie, TrieWalker};
+
 mod display_chars;
 use display_chars::DisplayChars;
 
 mod sound;
-use sound::Sound;
+use sound::{Sound, TSound};
+
+mod prosign;
+pub use prosign::Prosign;
+
+mod registry;
+pub use registry::{Registry, RegistryEntry};
+
+mod timing;
+pub use timing::Timing;
+
+mod unknown_char_policy;
+pub use unknown_char_policy::UnknownCharPolicy;
+
+mod wav;
 
 /// ## Main library struct.
 ///
@@ -56,67 +106,100 @@ use sound::Sound;
 #[derive(Debug, PartialEq, Clone)]
 pub struct Morse {
     morse: Vec<MorseChar>,
+    key: String,
     language: String,
     display_as: DisplayChars,
     sound: Sound,
-    from_char_converter: fn(char) -> Vec<MorseUnit>,
-    into_char_converter: fn(Vec<MorseUnit>) -> char,
+    unknown_char_policy: UnknownCharPolicy,
+    unencodable: Vec<(usize, char)>,
+    alphabet: Alphabet,
+    shifted: Option<Language>,
 }
 
 impl Morse {
-    /// Creates extended Morse Code struct.
+    /// Creates Morse Code struct with alphabet registered under the key in [Registry].
+    /// # Panics
+    ///
+    /// Panics if no alphabet is registered with the key.
+    /// Use [Morse::try_new] to handle it.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::Morse;
+    ///
+    /// let mut morse = Morse::new("uk");
+    /// morse.push_text("Ґанок");
+    ///
+    /// assert_eq!(morse.get_key(), "uk");
+    /// assert_eq!(morse.get_language(), "Ukrainian");
+    /// ```
+    pub fn new(key: &str) -> Morse {
+        Morse::try_new(key).unwrap_or_else(|err| panic!("{err}"))
+    }
+    /// Creates Morse Code struct with alphabet registered under the key in [Registry]
+    /// or return error if there is no such alphabet.
+    pub fn try_new(key: &str) -> Result<Morse, MorseError> {
+        Registry::get(key)
+            .map(Morse::from_entry)
+            .ok_or(MorseError::UnknownLanguage {
+                key: key.to_string(),
+                position: 0,
+            })
+    }
+    /// Creates extended Morse Code struct with [MorseTable] or conversion functions,
+    /// that are not registered. Language label is used as the key.
     /// # Examples
     ///
     /// ```
-    /// use morse_lib::{Morse, MorseUnit};
+    /// use morse_lib::{Alphabet, Morse, MorseUnit};
     /// use MorseUnit::{Dot, Line, Whitespace};
     ///
-    /// fn from_char(letter: char) -> Vec<MorseUnit> {
+    /// fn from_char(letter: char) -> Option<Vec<MorseUnit>> {
     ///     match letter {
-    ///         'a' => vec![Dot, Line],
-    ///         'б' => vec![Line, Dot, Dot, Dot],
-    ///         'в' => vec![Dot, Line, Line],
-    ///         'г' => vec![Dot, Dot, Dot, Dot],
-    ///         ' ' => vec![Whitespace],
-    ///           _ => panic!("Wrong character")
+    ///         'а' => Some(vec![Dot, Line]),
+    ///         'б' => Some(vec![Line, Dot, Dot, Dot]),
+    ///         'в' => Some(vec![Dot, Line, Line]),
+    ///         'г' => Some(vec![Line, Line, Dot]),
+    ///         ' ' => Some(vec![Whitespace]),
+    ///           _ => None
     ///     }
     /// }
     ///
-    /// fn into_char(letter: Vec<MorseUnit>) -> char {
-    ///     if letter.len() == 1 && letter[0] == Whitespace {
-    ///         return ' ';
-    ///     } else if letter.len() == 2 && letter[0] == Dot && letter[1] == Line {
-    ///         return 'а'
-    ///     } else if letter.len() == 3 && letter[0] == Dot && letter[1] == Line && letter[2] == Line {
-    ///         return 'в';
-    ///     } else if letter.len() == 4 {
-    ///         if letter[0] == Line && letter[1] == Dot && letter[2] == Dot && letter[3] == Dot {
-    ///             return 'б';
-    ///         } else {
-    ///             return 'г';
-    ///         }
-    ///     } else {
-    ///         panic!("Wrong Morse Char sequence")
+    /// fn into_char(letter: Vec<MorseUnit>) -> Option<char> {
+    ///     match letter.as_slice() {
+    ///         [Whitespace] => Some(' '),
+    ///         [Dot, Line] => Some('а'),
+    ///         [Line, Dot, Dot, Dot] => Some('б'),
+    ///         [Dot, Line, Line] => Some('в'),
+    ///         [Line, Line, Dot] => Some('г'),
+    ///         _ => None
     ///     }
     /// }
     ///
-    /// let morse = Morse::new("Ukrainian".to_string(), from_char, into_char);
+    /// let alphabet = Alphabet::converters(from_char, into_char);
+    /// let mut morse = Morse::with_alphabet("Ukrainian".to_string(), alphabet);
+    /// morse.push_text("баба");
+    ///
+    /// assert_eq!(morse.to_text(), "баба");
     /// ```
-    pub fn new(
-        language: String,
-        from_char: fn(char) -> Vec<MorseUnit>,
-        into_char: fn(Vec<MorseUnit>) -> char,
-    ) -> Morse {
+    pub fn with_alphabet(language: String, alphabet: impl Into<Alphabet>) -> Morse {
         Morse {
             morse: Vec::new(),
+            key: language.clone(),
             language,
             display_as: DisplayChars::default(),
             sound: Sound::default(),
-            from_char_converter: from_char,
-            into_char_converter: into_char,
+            unknown_char_policy: UnknownCharPolicy::default(),
+            unencodable: Vec::new(),
+            alphabet: alphabet.into(),
+            shifted: None,
         }
     }
     /// Creates International Morse Code struct from text.
+    /// # Panics
+    ///
+    /// Panics if text contains character without Morse Code representation.
+    /// Use [Morse::try_from_int_text] to handle it.
     /// # Examples
     ///
     /// ```
@@ -130,31 +213,310 @@ impl Morse {
     ///    );
     /// ```
     pub fn from_int_text(text: &str) -> Morse {
+        Morse::try_from_int_text(text).unwrap_or_else(|err| panic!("{err}"))
+    }
+    /// Creates International Morse Code struct from text or return error
+    /// with first character that can't be converted.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Morse, MorseError};
+    ///
+    /// assert_eq!(
+    ///        Morse::try_from_int_text("hi~"),
+    ///        Err(MorseError::UnknownChar { letter: '~', position: 2 })
+    ///    );
+    /// ```
+    pub fn try_from_int_text(text: &str) -> Result<Morse, MorseError> {
+        let mut morse = Morse::default();
+        morse.try_push_text(text)?;
+
+        Ok(morse)
+    }
+    /// Creates International Morse Code struct from text, handling characters
+    /// without Morse Code representation with given policy.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Morse, UnknownCharPolicy};
+    ///
+    /// let morse = Morse::from_int_text_with_policy("hi!", UnknownCharPolicy::Skip).unwrap();
+    ///
+    /// assert_eq!(morse.to_text(), "hi");
+    /// assert_eq!(morse.unencodable(), &[(2, '!')]);
+    /// ```
+    pub fn from_int_text_with_policy(
+        text: &str,
+        policy: UnknownCharPolicy,
+    ) -> Result<Morse, MorseError> {
+        let mut morse = Morse::default();
+        morse.unknown_char_policy(policy);
+        morse.try_push_text(text)?;
+
+        Ok(morse)
+    }
+    /// Creates Morse Code struct of built-in language from text.
+    /// # Panics
+    ///
+    /// Panics if text contains character without Morse Code representation.
+    /// Use [Morse::try_from_text_in] to handle it.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Language, Morse};
+    ///
+    /// let morse = Morse::from_text_in(Language::Russian, "сос");
+    ///
+    /// assert_eq!(
+    ///        morse.to_string(),
+    ///        ". . .   ⚊ ⚊ ⚊   . . ."
+    ///    );
+    /// ```
+    pub fn from_text_in(language: Language, text: &str) -> Morse {
+        Morse::try_from_text_in(language, text).unwrap_or_else(|err| panic!("{err}"))
+    }
+    /// Creates Morse Code struct of built-in language from text or return error
+    /// with first character that can't be converted.
+    pub fn try_from_text_in(language: Language, text: &str) -> Result<Morse, MorseError> {
+        let mut morse = Morse::from_entry(language.into());
+        morse.try_push_text(text)?;
+
+        Ok(morse)
+    }
+    /// Creates Morse Code struct with the same alphabet and settings from text.
+    /// Works with any alphabet, built-in, registered or not.
+    /// # Panics
+    ///
+    /// Panics if text contains character without Morse Code representation.
+    /// Use [Morse::try_with_text] to handle it.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Morse, MorseTable, MorseUnit};
+    /// use MorseUnit::{Dot, Line};
+    ///
+    /// let table = MorseTable::new([('а', vec![Dot, Line]), ('б', vec![Line, Dot, Dot, Dot])]).unwrap();
+    /// let morse = Morse::with_alphabet("Ukrainian".to_string(), table).with_text("баба");
+    ///
+    /// assert_eq!(morse.to_text(), "баба");
+    /// ```
+    pub fn with_text(self, text: &str) -> Morse {
+        self.try_with_text(text)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+    /// Creates Morse Code struct with the same alphabet and settings from text
+    /// or return error with first character that can't be converted.
+    pub fn try_with_text(mut self, text: &str) -> Result<Morse, MorseError> {
+        self.try_replace(text)?;
+
+        Ok(self)
+    }
+    /// Parse text into Morse Code and append it to the stored one.
+    /// # Panics
+    ///
+    /// Panics if text contains character without Morse Code representation.
+    /// Use [Morse::try_push_text] to handle it.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::Morse;
+    ///
+    /// let mut morse = Morse::from_int_text("sos");
+    /// morse.push_text(" qrz");
+    ///
+    /// assert_eq!(morse.to_text(), "sos qrz");
+    /// ```
+    pub fn push_text(&mut self, text: &str) {
+        self.try_push_text(text)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+    /// Parse text into Morse Code and append it to the stored one.
+    /// Prosigns are written as letters in angle brackets, e.g. `<SK>`.
+    /// Unicode direction marks are skipped.
+    /// Prosign that switches language, e.g. `<DO>` to Wabun code, is inserted
+    /// before character that only the other language could encode.
+    /// Characters without Morse Code representation are handled by
+    /// [UnknownCharPolicy] set with [Morse::unknown_char_policy].
+    /// On error stored Morse Code stays unchanged.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::Morse;
+    ///
+    /// let morse = Morse::from_int_text("QSL ホンジツ");
+    ///
+    /// assert_eq!(morse.to_text(), "QSL <DO>ホンジツ");
+    /// ```
+    pub fn try_push_text(&mut self, text: &str) -> Result<(), MorseError> {
         let mut morse: Vec<MorseChar> = Vec::new();
+        let mut unencodable: Vec<(usize, char)> = Vec::new();
+        let mut shifted = self.shifted;
 
-        for letter in text.chars() {
-            morse.push(MorseChar::from_char(letter, "International", from_int_char));
-        }
+        let letters: Vec<char> = text.chars().collect();
+        let mut idx = 0;
 
-        Morse {
-            morse,
-            ..Morse::default()
+        while idx < letters.len() {
+            // Direction marks of right-to-left text are invisible, there is nothing to send
+            if is_direction_mark(letters[idx]) {
+                idx += 1;
+                continue;
+            }
+            let (language, alphabet) = self.current_alphabet(shifted);
+            if let Some((prosign, len)) = parse_prosign(&letters[idx..]) {
+                morse.push(MorseChar::from_prosign(prosign, &language, &alphabet));
+                if let Some(target) = alphabet.shift_of(prosign) {
+                    shifted = self.shift_to(target);
+                }
+                idx += len;
+                continue;
+            }
+
+            if let Some((len, m_char)) = alphabet.encode_digraph(&letters[idx..]) {
+                let digraph: String = letters[idx..idx + len].iter().collect();
+                morse.push(MorseChar::from_digraph(&digraph, &language, m_char));
+                idx += len;
+                continue;
+            }
+
+            let letter = letters[idx];
+            let err = match MorseChar::from_char_parts(letter, &language, &alphabet) {
+                Ok(mut m_chars) => {
+                    morse.append(&mut m_chars);
+                    idx += 1;
+                    continue;
+                }
+                Err(err) => err,
+            };
+
+            // Switch to the language that can encode the character
+            let shift = alphabet.shifts().iter().find_map(|(prosign, target)| {
+                MorseChar::from_char_parts(letter, target.key(), &Alphabet::from(*target))
+                    .ok()
+                    .map(|m_chars| (*prosign, *target, m_chars))
+            });
+            match (shift, &self.unknown_char_policy) {
+                (Some((prosign, target, mut m_chars)), _) => {
+                    morse.push(MorseChar::from_prosign(prosign, &language, &alphabet));
+                    morse.append(&mut m_chars);
+                    shifted = self.shift_to(target);
+                }
+                (None, UnknownCharPolicy::Error) => return Err(err.at(idx)),
+                (None, UnknownCharPolicy::Skip) => unencodable.push((idx, letter)),
+                (None, UnknownCharPolicy::Substitute(m_char)) => {
+                    morse.push(MorseChar::new(letter, &language, m_char.clone()));
+                    unencodable.push((idx, letter));
+                }
+                (None, UnknownCharPolicy::Keep) => {
+                    morse.push(MorseChar::new(letter, &language, Vec::new()));
+                    unencodable.push((idx, letter));
+                }
+            }
+            idx += 1;
         }
+
+        self.morse.append(&mut morse);
+        self.unencodable = unencodable;
+        self.shifted = shifted;
+
+        Ok(())
     }
-    /// Parse text into Morse Code.
+    /// Parse text into Morse Code and append it to the stored one.
+    #[deprecated(note = "use push_text()")]
     pub fn parse_text(&mut self, text: &str) {
-        let mut morse: Vec<MorseChar> = Vec::new();
+        self.push_text(text)
+    }
+    /// Parse text into Morse Code and append it to the stored one.
+    #[deprecated(note = "use try_push_text()")]
+    pub fn try_parse_text(&mut self, text: &str) -> Result<(), MorseError> {
+        self.try_push_text(text)
+    }
+    /// Parse text into Morse Code instead of the stored one.
+    /// # Panics
+    ///
+    /// Panics if text contains character without Morse Code representation.
+    /// Use [Morse::try_replace] to handle it.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::Morse;
+    ///
+    /// let mut morse = Morse::from_int_text("sos");
+    /// morse.replace("qrz");
+    ///
+    /// assert_eq!(morse.to_text(), "qrz");
+    /// ```
+    pub fn replace(&mut self, text: &str) {
+        self.try_replace(text).unwrap_or_else(|err| panic!("{err}"))
+    }
+    /// Parse text into Morse Code instead of the stored one.
+    /// On error stored Morse Code stays unchanged.
+    pub fn try_replace(&mut self, text: &str) -> Result<(), MorseError> {
+        let mut morse = self.clone();
+        morse.clear();
+        morse.try_push_text(text)?;
+        *self = morse;
 
-        for letter in text.chars() {
-            morse.push(MorseChar::from_char(
-                letter,
-                &self.language,
-                self.from_char_converter,
-            ));
-        }
+        Ok(())
+    }
+    /// Remove stored Morse Code, alphabet and settings are kept.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::Morse;
+    ///
+    /// let mut morse = Morse::from_int_text("sos");
+    /// morse.clear();
+    ///
+    /// assert_eq!(morse.to_bin_str(), "");
+    /// ```
+    pub fn clear(&mut self) {
+        self.morse.clear();
+        self.unencodable.clear();
+        self.shifted = None;
+    }
+    /// Append prosign to the stored Morse Code.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Morse, Prosign};
+    ///
+    /// let mut morse = Morse::from_int_text("e");
+    /// morse.push_prosign(Prosign::SK);
+    ///
+    /// assert_eq!(morse.to_text(), "e<SK>");
+    /// assert_eq!(morse.to_string(), ".   . . . ⚊ . ⚊");
+    /// ```
+    pub fn push_prosign(&mut self, prosign: Prosign) {
+        self.morse
+            .push(MorseChar::from_prosign(prosign, &self.key, &self.alphabet));
+    }
+    /// Set policy for characters without Morse Code representation.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Morse, MorseUnit, UnknownCharPolicy};
+    ///
+    /// let mut morse = Morse::default();
+    /// morse.unknown_char_policy(UnknownCharPolicy::Substitute(vec![MorseUnit::Dot; 8]));
+    /// morse.push_text("e!");
+    ///
+    /// assert_eq!(morse.to_bin_str(), "1000101010101010101");
+    /// assert_eq!(morse.to_text(), "e!");
+    /// ```
+    pub fn unknown_char_policy(&mut self, policy: UnknownCharPolicy) {
+        self.unknown_char_policy = policy;
+    }
+    /// Return characters with their positions in text, that had no Morse Code
+    /// representation during the last text parsing.
+    pub fn unencodable(&self) -> &[(usize, char)] {
+        &self.unencodable
     }
 
     /// Creates International Morse Code struct from binary.
+    /// # Panics
+    ///
+    /// Panics if binary is malformed or contains unknown Morse Code.
+    /// Use [Morse::try_from_int_bin] to handle it.
     /// # Examples
     ///
     /// ```
@@ -168,58 +530,267 @@ impl Morse {
     ///    );
     /// ```
     pub fn from_int_bin(bin: &str) -> Morse {
-        let words: Vec<&str> = bin.split("0000000").collect();
-        let mut morse: Vec<MorseChar> = Vec::new();
+        Morse::try_from_int_bin(bin).unwrap_or_else(|err| panic!("{err}"))
+    }
+    /// Creates International Morse Code struct from binary or return error
+    /// with first bit group that can't be converted.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Morse, MorseError};
+    ///
+    /// assert_eq!(
+    ///        Morse::try_from_int_bin("1010100011011"),
+    ///        Err(MorseError::InvalidBinary { group: "11011".to_string(), position: 8 })
+    ///    );
+    /// ```
+    pub fn try_from_int_bin(bin: &str) -> Result<Morse, MorseError> {
+        let mut morse = Morse::default();
+        morse.try_push_bin(bin)?;
+
+        Ok(morse)
+    }
+    /// Creates Morse Code struct of built-in language from binary.
+    /// Letters with the same code as Latin ones are decoded into letters of the language.
+    /// # Panics
+    ///
+    /// Panics if binary is malformed or contains unknown Morse Code.
+    /// Use [Morse::try_from_bin_in] to handle it.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Language, Morse};
+    ///
+    /// let morse = Morse::from_bin_in(Language::Russian, "101010001110111011100010101");
+    ///
+    /// assert_eq!(morse.to_text(), "сос");
+    /// ```
+    pub fn from_bin_in(language: Language, bin: &str) -> Morse {
+        Morse::try_from_bin_in(language, bin).unwrap_or_else(|err| panic!("{err}"))
+    }
+    /// Creates Morse Code struct of built-in language from binary or return error
+    /// with first bit group that can't be converted.
+    pub fn try_from_bin_in(language: Language, bin: &str) -> Result<Morse, MorseError> {
+        let mut morse = Morse::from_entry(language.into());
+        morse.try_push_bin(bin)?;
+
+        Ok(morse)
+    }
+    /// Creates Morse Code struct with the same alphabet and settings from binary.
+    /// # Panics
+    ///
+    /// Panics if binary is malformed or contains unknown Morse Code.
+    /// Use [Morse::try_with_bin] to handle it.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::Morse;
+    ///
+    /// let morse = Morse::new("ru").with_bin("101010001110111011100010101");
+    ///
+    /// assert_eq!(morse.to_text(), "сос");
+    /// ```
+    pub fn with_bin(self, bin: &str) -> Morse {
+        self.try_with_bin(bin).unwrap_or_else(|err| panic!("{err}"))
+    }
+    /// Creates Morse Code struct with the same alphabet and settings from binary
+    /// or return error with first bit group that can't be converted.
+    pub fn try_with_bin(mut self, bin: &str) -> Result<Morse, MorseError> {
+        self.try_replace_bin(bin)?;
 
-        for word in words {
-            let letters: Vec<&str> = word.split("000").collect();
+        Ok(self)
+    }
+    /// Parse binary into Morse Code and append it to the stored one.
+    /// # Panics
+    ///
+    /// Panics if binary is malformed or contains unknown Morse Code.
+    /// Use [Morse::try_push_bin] to handle it.
+    pub fn push_bin(&mut self, bin: &str) {
+        self.try_push_bin(bin).unwrap_or_else(|err| panic!("{err}"))
+    }
+    /// Parse binary into Morse Code and append it to the stored one.
+    /// After prosign that switches language, e.g. `<DO>` to Wabun code,
+    /// codes are decoded in the other language.
+    /// On error stored Morse Code stays unchanged.
+    pub fn try_push_bin(&mut self, bin: &str) -> Result<(), MorseError> {
+        let mut morse: Vec<MorseChar> = Vec::new();
+        let mut shifted = self.shifted;
 
-            for letter in letters {
-                morse.push(MorseChar::from_bin(letter, "International", into_int_char));
+        for (position, letter) in split_bin(bin)? {
+            let (language, alphabet) = self.current_alphabet(shifted);
+            let m_char = MorseChar::from_bin(letter, &language, &alphabet)
+                .map_err(|err| err.at(position))?;
+            if let Symbol::Prosign(prosign) = m_char.get_symbol() {
+                if let Some(target) = alphabet.shift_of(*prosign) {
+                    shifted = self.shift_to(target);
+                }
             }
+            morse.push(m_char);
         }
 
-        Morse {
-            morse,
-            ..Morse::default()
-        }
+        self.morse.append(&mut morse);
+        self.shifted = shifted;
+
+        Ok(())
     }
-    /// Parse binary into Morse Code.
+    /// Parse binary into Morse Code and append it to the stored one.
+    #[deprecated(note = "use push_bin()")]
     pub fn parse_bin(&mut self, bin: &str) {
-        let words: Vec<&str> = bin.split("0000000").collect();
+        self.push_bin(bin)
+    }
+    /// Parse binary into Morse Code and append it to the stored one.
+    #[deprecated(note = "use try_push_bin()")]
+    pub fn try_parse_bin(&mut self, bin: &str) -> Result<(), MorseError> {
+        self.try_push_bin(bin)
+    }
+    /// Parse binary into Morse Code instead of the stored one.
+    /// # Panics
+    ///
+    /// Panics if binary is malformed or contains unknown Morse Code.
+    /// Use [Morse::try_replace_bin] to handle it.
+    pub fn replace_bin(&mut self, bin: &str) {
+        self.try_replace_bin(bin)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+    /// Parse binary into Morse Code instead of the stored one.
+    /// On error stored Morse Code stays unchanged.
+    pub fn try_replace_bin(&mut self, bin: &str) -> Result<(), MorseError> {
+        let mut morse = self.clone();
+        morse.clear();
+        morse.try_push_bin(bin)?;
+        *self = morse;
 
-        for word in words {
-            let letters: Vec<&str> = word.split("000").collect();
+        Ok(())
+    }
 
-            for letter in letters {
-                self.morse.push(MorseChar::from_bin(
-                    letter,
-                    &self.language,
-                    self.into_char_converter,
-                ));
-            }
-        }
+    /// Decode Morse Code and encode it again with built-in language,
+    /// e.g. to compare American Morse Code with International one.
+    /// # Panics
+    ///
+    /// Panics if decoded text contains character without Morse Code representation
+    /// in the language. Use [Morse::try_transcode_in] to handle it.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Language, Morse};
+    ///
+    /// let american = Morse::from_text_in(Language::American, "code");
+    /// let international = american.transcode_in(Language::International);
+    ///
+    /// assert_eq!(american.to_string(), ". .  .   .  .   ⚊ . .   .");
+    /// assert_eq!(international.to_string(), "⚊ . ⚊ .   ⚊ ⚊ ⚊   ⚊ . .   .");
+    /// ```
+    pub fn transcode_in(&self, language: Language) -> Morse {
+        self.try_transcode_in(language)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+    /// Decode Morse Code and encode it again with built-in language or return error
+    /// with first character that can't be converted.
+    pub fn try_transcode_in(&self, language: Language) -> Result<Morse, MorseError> {
+        let RegistryEntry {
+            key,
+            name,
+            alphabet,
+        } = language.into();
+        let mut morse = self.try_transcode(name, alphabet)?;
+        morse.key = key;
+
+        Ok(morse)
+    }
+    /// Decode Morse Code and encode it again with [MorseTable] or conversion functions.
+    /// Output settings and policy for unknown characters are kept.
+    pub fn try_transcode(
+        &self,
+        language: String,
+        alphabet: impl Into<Alphabet>,
+    ) -> Result<Morse, MorseError> {
+        let mut morse = Morse {
+            morse: Vec::new(),
+            key: language.clone(),
+            language,
+            display_as: self.display_as.clone(),
+            sound: self.sound.clone(),
+            unknown_char_policy: self.unknown_char_policy.clone(),
+            unencodable: Vec::new(),
+            alphabet: alphabet.into(),
+            shifted: None,
+        };
+        morse.try_push_text(&self.to_text())?;
+
+        Ok(morse)
     }
 
     /// Play sound that represent Morse Code.
     pub fn to_beep(&self) {
-        let morse = RefCell::new(self.morse.clone());
-        for (idx, m_char) in morse.borrow_mut().iter_mut().enumerate() {
-            m_char.frequency(self.sound.frequency);
-            m_char.play_speed(self.sound.speed);
-
-            m_char.to_beep();
+        let keying = self.sound.timing.keying(&self.to_bin_str());
+        self.sound.play(self.sound.frequency, &keying);
+    }
+    /// Return duration of Morse Code sent with current [Timing].
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use morse_lib::{Morse, Timing};
+    ///
+    /// let mut morse = Morse::from_int_text("e e");
+    /// morse.timing(Timing::new(20.0));
+    ///
+    /// // dot, word gap, dot
+    /// assert_eq!(morse.duration(), Duration::from_millis(540));
+    /// ```
+    pub fn duration(&self) -> time::Duration {
+        self.sound
+            .timing
+            .keying(&self.to_bin_str())
+            .iter()
+            .map(|(_, duration)| *duration)
+            .sum()
+    }
+    /// Render Morse Code into mono samples of keyed tone without audio device,
+    /// with current frequency and [Timing]. Samples are in range from -1 to 1.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Morse, Timing};
+    ///
+    /// let mut morse = Morse::from_int_text("e e");
+    /// morse.timing(Timing::new(20.0));
+    /// let samples = morse.render_samples(8000);
+    ///
+    /// // 540 ms
+    /// assert_eq!(samples.len(), 4320);
+    /// assert!(samples[480..3840].iter().all(|sample| *sample == 0.0));
+    /// ```
+    pub fn render_samples(&self, sample_rate: u32) -> Vec<f32> {
+        let keying = self.sound.timing.keying(&self.to_bin_str());
 
-            // The space between letters is three units
-            if idx < self.morse.len() - 1 {
-                thread::sleep(time::Duration::from_secs(3));
-            }
-        }
+        self.sound
+            .render(self.sound.frequency, &keying, sample_rate)
+    }
+    /// Render Morse Code into mono 16-bit samples of keyed tone without audio device.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::Morse;
+    ///
+    /// let samples = Morse::from_int_text("sos").render_samples_i16(44100);
+    ///
+    /// assert_eq!(samples.len(), Morse::from_int_text("sos").render_samples(44100).len());
+    /// ```
+    pub fn render_samples_i16(&self, sample_rate: u32) -> Vec<i16> {
+        self.render_samples(sample_rate)
+            .into_iter()
+            .map(|sample| (sample * f32::from(i16::MAX)).round() as i16)
+            .collect()
     }
     /// Return String value that contains stored language label.
     pub fn get_language(&self) -> String {
         self.language.clone()
     }
+    /// Return registry key of the alphabet, see [Registry].
+    pub fn get_key(&self) -> String {
+        self.key.clone()
+    }
     /// Creates alias for dot in output string.
     /// # Examples
     ///
@@ -283,10 +854,10 @@ impl Morse {
     pub fn frequency(&mut self, frequency: f32) {
         self.sound.frequency = frequency;
     }
-    /// Set sound speed.
-    /// 1 - normal speed
-    /// > 1 - faster
-    /// < 1 - slower
+    /// Set sound speed relative to [Timing::DEFAULT_WPM].
+    /// - 1 - normal speed
+    /// - more than 1 - faster
+    /// - less than 1 - slower
     /// # Examples
     ///
     /// ```
@@ -296,7 +867,39 @@ impl Morse {
     /// morse.play_speed(2.0);
     /// ```
     pub fn play_speed(&mut self, speed: f32) {
-        self.sound.speed = speed;
+        self.sound.timing = Timing::new(Timing::DEFAULT_WPM * speed);
+    }
+    /// Set speed of playback and durations of elements.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Morse, Timing};
+    ///
+    /// let mut morse = Morse::from_int_text("sos");
+    /// morse.timing(Timing::new(25.0));
+    ///
+    /// assert_eq!(morse.get_timing().dot().as_millis(), 48);
+    /// ```
+    pub fn timing(&mut self, timing: Timing) {
+        self.sound.timing = timing;
+    }
+    /// Return speed of playback and durations of elements.
+    pub fn get_timing(&self) -> Timing {
+        self.sound.timing
+    }
+    /// Set rise and fall of tone in playback and rendering,
+    /// by default it is raised cosine of 5 ms.
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use morse_lib::{Envelope, Morse};
+    ///
+    /// let mut morse = Morse::from_int_text("sos");
+    /// morse.envelope(Envelope::blackman(Duration::from_millis(8)));
+    /// ```
+    pub fn envelope(&mut self, envelope: Envelope) {
+        self.sound.envelope = envelope;
     }
     /// Creates binary-formatted Morse Code.
     /// # Examples
@@ -313,12 +916,13 @@ impl Morse {
     /// ```
     pub fn to_bin_str(&self) -> String {
         let mut string = String::new();
+        let morse = self.encoded_chars();
 
-        for (idx, m_char) in self.morse.iter().enumerate() {
+        for (idx, m_char) in morse.iter().enumerate() {
             string.push_str(&m_char.to_bin_str());
 
             // The space between letters is three units
-            if idx < self.morse.len() - 1 {
+            if idx < morse.len() - 1 {
                 string.push_str("000");
             }
         }
@@ -342,45 +946,95 @@ impl Morse {
     pub fn to_text(&self) -> String {
         let mut text = String::new();
 
-        for m_char in &self.morse {
-            text.push(m_char.get_letter());
+        // Characters are joined by rules of the language they were sent in,
+        // e.g. Wabun base kana and voiced mark become voiced kana
+        for group in self
+            .morse
+            .chunk_by(|m_char, next| m_char.get_language() == next.get_language())
+        {
+            let symbols: String = group
+                .iter()
+                .map(|m_char| m_char.get_symbol().to_string())
+                .collect();
+            let key = group[0].get_language();
+
+            if key == self.key {
+                text.push_str(&self.alphabet.compose(&symbols));
+            } else {
+                match Registry::get(key) {
+                    Some(entry) => text.push_str(&entry.alphabet.compose(&symbols)),
+                    None => text.push_str(&symbols),
+                }
+            }
         }
 
         text
     }
+
+    fn from_entry(entry: RegistryEntry) -> Morse {
+        let mut morse = Morse::with_alphabet(entry.name, entry.alphabet);
+        morse.key = entry.key;
+
+        morse
+    }
+
+    // Key and alphabet used after switching prosign
+    fn current_alphabet(&self, shifted: Option<Language>) -> (String, Alphabet) {
+        match shifted {
+            Some(language) => (language.key().to_string(), language.into()),
+            None => (self.key.clone(), self.alphabet.clone()),
+        }
+    }
+
+    // Switching back to own language ends the shift
+    fn shift_to(&self, target: Language) -> Option<Language> {
+        (target.key() != self.key).then_some(target)
+    }
+
+    // Characters kept without Morse Code have nothing to show or play
+    fn encoded_chars(&self) -> Vec<MorseChar> {
+        self.morse
+            .iter()
+            .filter(|m_char| !m_char.is_empty())
+            .cloned()
+            .collect()
+    }
 }
 
 impl Default for Morse {
     fn default() -> Self {
         Self {
             morse: Vec::new(),
-            language: "International".to_string(),
+            key: Language::International.key().to_string(),
+            language: Language::International.name().to_string(),
             display_as: DisplayChars::default(),
             sound: Sound::default(),
-            from_char_converter: from_int_char,
-            into_char_converter: into_int_char,
+            unknown_char_policy: UnknownCharPolicy::default(),
+            unencodable: Vec::new(),
+            alphabet: Alphabet::default(),
+            shifted: None,
         }
     }
 }
 
-impl ToString for Morse {
-    fn to_string(&self) -> String {
-        let mut string = String::new();
-        let morse = RefCell::new(self.morse.clone());
+impl fmt::Display for Morse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let morse = RefCell::new(self.encoded_chars());
+        let len = morse.borrow().len();
 
         for (idx, m_char) in morse.borrow_mut().iter_mut().enumerate() {
             m_char.dot_as(&self.display_as.dot);
             m_char.line_as(&self.display_as.line);
             m_char.whitespace_as(&self.display_as.whitespace);
-            string.push_str(&m_char.to_string());
+            write!(f, "{m_char}")?;
 
             // The space between letters is three units
-            if idx < self.morse.len() - 1 {
-                string.push_str("   ");
+            if idx < len - 1 {
+                f.write_str("   ")?;
             }
         }
 
-        string
+        Ok(())
     }
 }
 
@@ -414,6 +1068,58 @@ mod morse_tests {
         );
     }
 
+    #[test]
+    fn create_by_key() {
+        let mut morse = Morse::new("ru");
+        morse.push_text("Привет");
+
+        assert_eq!(morse.get_key(), "ru");
+        assert_eq!(morse.get_language(), "Russian");
+        assert_eq!(Morse::from_int_text("e").get_key(), "en");
+
+        let mut restored = Morse::new(&morse.get_key());
+        restored.push_bin(&morse.to_bin_str());
+        assert_eq!(restored.to_text(), "привет");
+
+        assert_eq!(
+            Morse::try_new("xx").err(),
+            Some(MorseError::UnknownLanguage {
+                key: "xx".to_string(),
+                position: 0
+            })
+        );
+    }
+
+    #[test]
+    fn farnsworth_duration() {
+        let mut morse = Morse::from_int_text("paris ");
+        morse.timing(Timing::new(10.0));
+        let standard = morse.duration();
+        morse.timing(Timing::farnsworth(20.0, 10.0));
+
+        // Binary of trailing space has no gap after it
+        assert!((standard.as_secs_f64() - 6.0 + 0.36).abs() < 1e-6);
+        assert!((morse.duration().as_secs_f64() - 6.0 + 3.0 * 4.14 / 19.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn render_samples() {
+        let mut morse = Morse::from_int_text("sos");
+        morse.frequency(1000.0);
+        morse.timing(Timing::farnsworth(20.0, 10.0));
+        let samples = morse.render_samples(44100);
+        let tone = samples.iter().filter(|sample| **sample != 0.0).count();
+
+        assert_eq!(
+            samples.len(),
+            (morse.duration().as_secs_f64() * 44100.0).round() as usize
+        );
+        // Nine dots and dashes of 60 and 180 ms, few samples cross zero
+        assert!(tone.abs_diff(((0.06 * 6.0 + 0.18 * 3.0) * 44100.0) as usize) < 100);
+        assert_eq!(morse.render_samples_i16(44100).len(), samples.len());
+        assert!(Morse::default().render_samples(44100).is_empty());
+    }
+
     #[test]
     fn to_string() {
         assert_eq!(
@@ -439,4 +1145,520 @@ mod morse_tests {
 
         assert_eq!(morse.to_string(), "🔥 🔥 🔥 🔥   🔥 🔥   🚧   🔥 🔥 ➖");
     }
+
+    #[test]
+    fn try_from_text_str() {
+        assert_eq!(
+            Morse::try_from_int_text("hi!"),
+            Err(MorseError::UnknownChar {
+                letter: '!',
+                position: 2
+            })
+        );
+        assert!(Morse::try_from_int_text("hi").is_ok());
+    }
+
+    #[test]
+    fn try_from_binary_str() {
+        assert_eq!(
+            Morse::try_from_int_bin("10101000102"),
+            Err(MorseError::InvalidBinary {
+                group: "2".to_string(),
+                position: 10
+            })
+        );
+        assert_eq!(
+            Morse::try_from_int_bin("1010100011111111"),
+            Err(MorseError::InvalidBinary {
+                group: "11111111".to_string(),
+                position: 8
+            })
+        );
+        assert_eq!(
+            Morse::try_from_int_bin("10100010101010101"),
+            Err(MorseError::UnknownCode {
+                code: vec![MorseUnit::Dot; 6],
+                position: 6
+            })
+        );
+        assert_eq!(
+            Morse::try_from_int_bin("10100000"),
+            Err(MorseError::InvalidBinary {
+                group: "00000".to_string(),
+                position: 3
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_char_policies() {
+        let text = "hi# u!";
+
+        assert_eq!(
+            Morse::from_int_text_with_policy(text, UnknownCharPolicy::Error),
+            Err(MorseError::UnknownChar {
+                letter: '#',
+                position: 2
+            })
+        );
+
+        let skipped = Morse::from_int_text_with_policy(text, UnknownCharPolicy::Skip).unwrap();
+        assert_eq!(skipped.to_text(), "hi u");
+        assert_eq!(
+            skipped.to_bin_str(),
+            Morse::from_int_text("hi u").to_bin_str()
+        );
+        assert_eq!(skipped.unencodable(), &[(2, '#'), (5, '!')]);
+
+        let error_sign = vec![MorseUnit::Dot; 8];
+        let substituted =
+            Morse::from_int_text_with_policy(text, UnknownCharPolicy::Substitute(error_sign))
+                .unwrap();
+        assert_eq!(substituted.to_text(), text);
+        assert_eq!(
+            substituted.to_string(),
+            ". . . .   . .   . . . . . . . .       . . ⚊   . . . . . . . ."
+        );
+
+        let kept = Morse::from_int_text_with_policy(text, UnknownCharPolicy::Keep).unwrap();
+        assert_eq!(kept.to_text(), text);
+        assert_eq!(kept.to_string(), skipped.to_string());
+        assert_eq!(kept.to_bin_str(), skipped.to_bin_str());
+        assert_eq!(kept.unencodable(), skipped.unencodable());
+    }
+
+    #[test]
+    fn punctuation_round_trip() {
+        const TEXT: &str = "é.,:?'-/()\"=+@ john.doe@mail.com (12:30) 2+2=4?";
+
+        let bin = Morse::from_int_text(TEXT).to_bin_str();
+        assert_eq!(Morse::from_int_bin(&bin).to_text(), TEXT);
+
+        // Multiplication sign is transmitted as letter X
+        assert_eq!(
+            Morse::from_int_text("2×2").to_bin_str(),
+            Morse::from_int_text("2x2").to_bin_str()
+        );
+        assert_eq!(
+            Morse::from_int_bin(&Morse::from_int_text("2×2").to_bin_str()).to_text(),
+            "2x2"
+        );
+    }
+
+    #[test]
+    fn prosigns() {
+        let morse = Morse::from_int_text("<KA>cq de r1<sos><AR> <VE><SK>");
+
+        assert_eq!(morse.to_text(), "<CT>cq de r1<SOS><AR> <SN><SK>");
+        // Letters take precedence over prosigns with the same code
+        assert_eq!(
+            Morse::from_int_bin(&morse.to_bin_str()).to_text(),
+            "<CT>cq de r1<SOS>+ <SN><SK>"
+        );
+        assert_eq!(
+            Morse::from_int_text("<SOS>").to_bin_str(),
+            "10101011101110111010101"
+        );
+        assert_eq!(
+            Morse::try_from_int_text("<XY>"),
+            Err(MorseError::UnknownChar {
+                letter: '<',
+                position: 0
+            })
+        );
+    }
+
+    #[test]
+    fn custom_table() {
+        use MorseUnit::{Dot, Line, Whitespace};
+
+        let table = MorseTable::new([
+            ('а', vec![Dot, Line]),
+            ('б', vec![Line, Dot, Dot, Dot]),
+            (' ', vec![Whitespace]),
+        ])
+        .unwrap();
+        let mut morse = Morse::with_alphabet("Ukrainian".to_string(), table);
+        morse.push_text("Аба ба<AR>");
+        let bin = morse.to_bin_str();
+
+        let mut decoded = morse.clone();
+        decoded.push_bin(&bin);
+        assert_eq!(decoded.to_text(), "Аба ба<AR>аба ба<AR>");
+        assert_eq!(
+            morse.try_push_text("в"),
+            Err(MorseError::UnknownChar {
+                letter: 'в',
+                position: 0
+            })
+        );
+    }
+
+    #[test]
+    fn mutation_of_custom_languages() {
+        use MorseUnit::{Dot, Line, Whitespace};
+
+        fn from_char(letter: char) -> Option<Vec<MorseUnit>> {
+            match letter {
+                'а' => Some(vec![Dot, Line]),
+                'б' => Some(vec![Line, Dot, Dot, Dot]),
+                ' ' => Some(vec![Whitespace]),
+                _ => None,
+            }
+        }
+        fn into_char(m_char: Vec<MorseUnit>) -> Option<char> {
+            match m_char.as_slice() {
+                [Dot, Line] => Some('а'),
+                [Line, Dot, Dot, Dot] => Some('б'),
+                [Whitespace] => Some(' '),
+                _ => None,
+            }
+        }
+
+        let table = MorseTable::new([
+            ('а', vec![Dot, Line]),
+            ('б', vec![Line, Dot, Dot, Dot]),
+            (' ', vec![Whitespace]),
+        ])
+        .unwrap();
+        let alphabets = [
+            Alphabet::from(table),
+            Alphabet::converters(from_char, into_char),
+            Alphabet::custom((from_char, into_char)),
+        ];
+
+        for alphabet in alphabets {
+            let empty = Morse::with_alphabet("Ukrainian".to_string(), alphabet);
+            let mut morse = empty.clone().with_text("аб");
+            assert_eq!(morse.to_text(), "аб");
+
+            morse.push_text(" ба");
+            assert_eq!(morse.to_text(), "аб ба");
+            let bin = morse.to_bin_str();
+
+            morse.replace("б");
+            assert_eq!(morse.to_text(), "б");
+            assert_eq!(
+                morse.try_replace("бв"),
+                Err(MorseError::UnknownChar {
+                    letter: 'в',
+                    position: 1
+                })
+            );
+            assert_eq!(morse.to_text(), "б");
+
+            morse.push_bin(&bin);
+            assert_eq!(morse.to_text(), "баб ба");
+            morse.replace_bin(&bin);
+            assert_eq!(morse.to_text(), "аб ба");
+            assert!(morse.try_replace_bin("11").is_err());
+            assert_eq!(morse.to_text(), "аб ба");
+
+            morse.clear();
+            assert_eq!(morse, empty);
+            assert_eq!(empty.with_bin(&bin).to_text(), "аб ба");
+        }
+    }
+
+    #[test]
+    fn boxed_alphabet() {
+        use MorseUnit::{Dot, Line};
+
+        let codes: Vec<(char, Vec<MorseUnit>)> = vec![('x', vec![Line, Dot]), ('y', vec![Dot])];
+        let lookup = codes.clone();
+        let alphabet: Box<dyn MorseAlphabet> = Box::new((
+            move |letter: char| {
+                lookup
+                    .iter()
+                    .find(|(l, _)| *l == letter)
+                    .map(|(_, code)| code.clone())
+            },
+            move |m_char: Vec<MorseUnit>| {
+                codes
+                    .iter()
+                    .find(|(_, code)| *code == m_char)
+                    .map(|(l, _)| *l)
+            },
+        ));
+        let mut morse = Morse::with_alphabet("XY".to_string(), alphabet);
+        morse.push_text("xy");
+
+        let mut decoded = morse.clone();
+        decoded.push_bin(&morse.to_bin_str());
+        assert_eq!(decoded.to_text(), "xyxy");
+        assert_eq!(morse.get_language(), "XY");
+    }
+
+    #[test]
+    fn cyrillic_alphabets_round_trip() {
+        let texts = [
+            (
+                Language::Russian,
+                "съешь же ещё этих мягких французских булок, да выпей чаю. 1945",
+                "съешь же еще этих мягких французских булок, да выпей чаю. 1945",
+            ),
+            (
+                Language::Ukrainian,
+                "Чуєш їх, доцю, га? Кумедна ж ти, прощайся без ґольфів. 2024",
+                "чуєш їх, доцю, га? кумедна ж ти, прощайся без ґольфів. 2024",
+            ),
+            (
+                Language::Belarusian,
+                "У Іўі худы жвавы чорт у зялёнай камізэльцы пабег пад'есці фаршу з юшкай",
+                "у іўі худы жвавы чорт у зяленай камізэльцы пабег пад'есці фаршу з юшкай",
+            ),
+        ];
+
+        for (language, text, decoded) in texts {
+            let bin = Morse::from_text_in(language, text).to_bin_str();
+
+            assert_eq!(Morse::from_bin_in(language, &bin).to_text(), decoded);
+            assert_eq!(
+                Morse::from_bin_in(language, &bin).get_language(),
+                language.name()
+            );
+        }
+    }
+
+    #[test]
+    fn greek_hebrew_arabic_alphabets_round_trip() {
+        let texts = [
+            (Language::Greek, "αβγδεζηθικλμνξοπρστυφχψω 0123456789"),
+            (Language::Hebrew, "אבגדהוזחטיכלמנסעפצקרשת 0123456789"),
+            (Language::Arabic, "ابتثجحخدذرزسشصضطظعغفقكلمنهويء 0123456789"),
+        ];
+
+        for (language, text) in texts {
+            let bin = Morse::from_text_in(language, text).to_bin_str();
+
+            assert_eq!(Morse::from_bin_in(language, &bin).to_text(), text);
+        }
+    }
+
+    #[test]
+    fn letter_variants_are_sent_as_base_letters() {
+        let decode = |language, text| {
+            let bin = Morse::from_text_in(language, text).to_bin_str();
+            Morse::from_bin_in(language, &bin).to_text()
+        };
+
+        assert_eq!(
+            decode(Language::Greek, "Καλημέρα κόσμε\u{037E}"),
+            "καλημερα κοσμε?"
+        );
+        // Final forms are restored at the end of words
+        assert_eq!(decode(Language::Greek, "ο κόσμος"), "ο κοσμος");
+        assert_eq!(decode(Language::Hebrew, "שלום עולם"), "שלום עולם");
+        assert_eq!(decode(Language::Hebrew, "ארץ, כף"), "ארץ, כף");
+        assert_eq!(decode(Language::Hebrew, "מ"), "מ");
+        assert_eq!(decode(Language::Arabic, "مدرسة ٢٠٢٤"), "مدرسه 2024");
+    }
+
+    #[test]
+    fn right_to_left_text_is_in_logical_order() {
+        assert!(Language::Hebrew.is_right_to_left());
+        assert!(Language::Arabic.is_right_to_left());
+        assert!(!Language::Greek.is_right_to_left());
+
+        // First sent letter is the first letter of the word
+        let morse = Morse::from_text_in(Language::Hebrew, "\u{202B}אב\u{200F}\u{202C}");
+        assert!(morse.to_bin_str().starts_with("10111"));
+        assert_eq!(morse.to_text(), "אב");
+        assert_eq!(
+            Morse::from_bin_in(Language::Arabic, "1011100011101010100010101").to_text(),
+            "ابس"
+        );
+    }
+
+    #[test]
+    fn wabun_round_trip() {
+        let text = "イロハニホヘト チリヌルヲ 1945";
+        let bin = Morse::from_text_in(Language::Wabun, text).to_bin_str();
+        assert_eq!(Morse::from_bin_in(Language::Wabun, &bin).to_text(), text);
+
+        // Hiragana and small kana are sent as katakana, voiced kana as two codes
+        let morse = Morse::from_text_in(Language::Wabun, "がっこう パン");
+        assert_eq!(
+            morse.to_bin_str(),
+            Morse::from_text_in(Language::Wabun, "カ\u{309B}ツコウ ハ\u{309C}ン").to_bin_str()
+        );
+        assert_eq!(
+            Morse::from_bin_in(Language::Wabun, &morse.to_bin_str()).to_text(),
+            "ガツコウ パン"
+        );
+    }
+
+    #[test]
+    fn wabun_switching() {
+        let morse = Morse::from_int_text("QSL <DO>ホンジツ<SN> OK");
+        assert_eq!(morse.to_text(), "QSL <DO>ホンジツ<SN> OK");
+
+        // Same codes are decoded as kana between <DO> and <SN>
+        let decoded = Morse::from_int_bin(&morse.to_bin_str());
+        assert_eq!(decoded.to_text(), "qsl <DO>ホンジツ<SN> ok");
+        assert_eq!(
+            Morse::from_int_bin("1110001110101011101110111000111010001010101110100011101")
+                .to_text(),
+            "t<DO>タ<SN>n"
+        );
+
+        // Switching prosigns are inserted when needed
+        assert_eq!(
+            Morse::from_int_text("QSL ホンジツ OK").to_text(),
+            "QSL <DO>ホンジツ <SN>OK"
+        );
+        assert_eq!(
+            Morse::from_text_in(Language::Wabun, "ホ QSL").to_text(),
+            "ホ <SN>QSL"
+        );
+        // Voiced kana right after inserted prosign is joined back too
+        assert_eq!(Morse::from_int_text("QSL ジツ").to_text(), "QSL <DO>ジツ");
+    }
+
+    #[test]
+    fn korean_round_trip() {
+        let decode = |text| {
+            let bin = Morse::from_text_in(Language::Korean, text).to_bin_str();
+            Morse::from_bin_in(Language::Korean, &bin).to_text()
+        };
+
+        for text in [
+            "안녕하세요",
+            "괜찮아? 있어요. 닭 값 1950",
+            "아빠 까치 쌀 짜다 뚫다",
+            "ㅋㅋㅋ",
+        ] {
+            assert_eq!(decode(text), text);
+        }
+
+        // Syllables are sent as jamo
+        assert_eq!(
+            Morse::from_text_in(Language::Korean, "한").to_bin_str(),
+            Morse::from_text_in(Language::Korean, "ㅎㅏㄴ").to_bin_str()
+        );
+        assert_eq!(
+            Morse::from_text_in(Language::Korean, "ㅎㅏㄴ").to_text(),
+            "한"
+        );
+    }
+
+    #[test]
+    fn extended_latin_alphabets_round_trip() {
+        let texts = [
+            (Language::German, "Größe Übermaß Äpfel ich"),
+            (Language::Spanish, "Año pingüino"),
+            (Language::Danish, "Ærø på Øster"),
+            (Language::Swedish, "Åsa äter öl"),
+            (Language::Polish, "Zażółć gęślą jaźń"),
+            (Language::Turkish, "Çağrı öğüş"),
+        ];
+
+        for (language, text) in texts {
+            let bin = Morse::from_text_in(language, text).to_bin_str();
+
+            assert_eq!(
+                Morse::from_bin_in(language, &bin).to_text(),
+                text.to_lowercase().replace("ı", "i")
+            );
+        }
+
+        // CH is sent with single code
+        assert_eq!(
+            Morse::from_text_in(Language::German, "Ch").to_bin_str(),
+            "111011101110111"
+        );
+        assert_eq!(Morse::from_text_in(Language::German, "Ch").to_text(), "Ch");
+        // Ä and Æ share code
+        assert_eq!(
+            Morse::from_text_in(Language::Norwegian, "ä").to_bin_str(),
+            Morse::from_text_in(Language::Swedish, "æ").to_bin_str()
+        );
+    }
+
+    #[test]
+    fn accented_letters_are_transliterated() {
+        assert_eq!(Morse::from_int_text("Größe").to_text(), "Grosse");
+        assert_eq!(
+            Morse::from_int_bin(&Morse::from_int_text("Ñandú Łódź").to_bin_str()).to_text(),
+            "nandu lodz"
+        );
+        // Letter of the language is kept, others are transliterated
+        assert_eq!(
+            Morse::from_text_in(Language::Spanish, "Ñandú Œuvre").to_text(),
+            "Ñandu OEuvre"
+        );
+        assert_eq!(Morse::from_text_in(Language::Polish, "é").to_text(), "e");
+        assert_eq!(Morse::from_int_text("é").to_text(), "é");
+    }
+
+    #[test]
+    fn american_morse() {
+        let text = "the quick brown fox jumps over the lazy dog. 1850, 1920!";
+        let bin = Morse::from_text_in(Language::American, text).to_bin_str();
+        assert_eq!(Morse::from_bin_in(Language::American, &bin).to_text(), text);
+
+        // Space inside letter is two units, long lines are five and seven units
+        assert_eq!(
+            Morse::from_text_in(Language::American, "co").to_bin_str(),
+            "1010010001001"
+        );
+        assert_eq!(
+            Morse::from_text_in(Language::American, "l0").to_bin_str(),
+            "111110001111111"
+        );
+        assert_eq!(
+            Morse::from_text_in(Language::American, "c l0").to_string(),
+            ". .  .       ⚊⚊   ⚊⚊⚊"
+        );
+    }
+
+    #[test]
+    fn transcoding_between_tables() {
+        let american = Morse::from_text_in(Language::American, "Zoll");
+        let international = american.transcode_in(Language::International);
+
+        assert_eq!(international.get_language(), "International");
+        assert_eq!(international.to_text(), "Zoll");
+        assert_eq!(
+            international.to_bin_str(),
+            Morse::from_int_text("zoll").to_bin_str()
+        );
+        assert_eq!(
+            Morse::from_int_bin(&international.to_bin_str())
+                .transcode_in(Language::American)
+                .to_bin_str(),
+            american.to_bin_str()
+        );
+
+        let mut morse = Morse::from_int_text("a@b");
+        assert_eq!(
+            morse.try_transcode_in(Language::American),
+            Err(MorseError::UnknownChar {
+                letter: '@',
+                position: 1
+            })
+        );
+        morse.unknown_char_policy(UnknownCharPolicy::Skip);
+        assert_eq!(morse.transcode_in(Language::American).to_text(), "ab");
+    }
+
+    #[test]
+    fn whitespace_round_trip_through_binary() {
+        for text in ["hi u", " hi", "hi  ", "a  b", " "] {
+            let bin = Morse::from_int_text(text).to_bin_str();
+            assert_eq!(Morse::from_int_bin(&bin).to_text(), text);
+        }
+    }
+
+    #[test]
+    fn try_parse_keeps_morse_on_error() {
+        let mut morse = Morse::from_int_text("sos");
+
+        assert!(morse.try_push_text(" sos!").is_err());
+        assert!(morse.try_push_bin("10101000101012").is_err());
+        assert_eq!(morse.to_text(), "sos");
+
+        morse.push_text(" hi");
+        assert_eq!(morse.to_text(), "sos hi");
+    }
 }
@@ -0,0 +1,125 @@
+use std::fmt;
+
+use crate::MorseUnit;
+
+/// ## Conversion rules that could keep own state.
+///
+/// Implement it for a type holding e.g. substitution map or lookup table
+/// loaded at runtime and pass it to [Alphabet::custom](crate::Alphabet::custom).
+/// Pair of conversion functions or closures implements it too.
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use morse_lib::{Alphabet, Morse, MorseAlphabet, MorseUnit};
+/// use MorseUnit::{Dot, Line, Whitespace};
+///
+/// struct Substitution(HashMap<char, Vec<MorseUnit>>);
+///
+/// impl MorseAlphabet for Substitution {
+///     fn encode(&self, letter: char) -> Option<Vec<MorseUnit>> {
+///         self.0.get(&letter).cloned()
+///     }
+///     fn decode(&self, m_char: &[MorseUnit]) -> Option<char> {
+///         self.0
+///             .iter()
+///             .find(|(_, code)| code.as_slice() == m_char)
+///             .map(|(letter, _)| *letter)
+///     }
+///     fn symbols(&self) -> Vec<char> {
+///         self.0.keys().copied().collect()
+///     }
+/// }
+///
+/// let map = HashMap::from([('x', vec![Dot, Line]), (' ', vec![Whitespace])]);
+/// let mut morse = Morse::with_alphabet("X".to_string(), Alphabet::custom(Substitution(map)));
+/// morse.push_text("x x");
+///
+/// assert_eq!(morse.to_bin_str(), "10111000000010111");
+/// ```
+pub trait MorseAlphabet: Send + Sync {
+    /// Return Morse Code of character.
+    fn encode(&self, letter: char) -> Option<Vec<MorseUnit>>;
+    /// Return character of Morse Code.
+    fn decode(&self, m_char: &[MorseUnit]) -> Option<char>;
+    /// Return all characters that have Morse Code.
+    fn symbols(&self) -> Vec<char>;
+}
+
+impl<E, D> MorseAlphabet for (E, D)
+where
+    E: Fn(char) -> Option<Vec<MorseUnit>> + Send + Sync,
+    D: Fn(Vec<MorseUnit>) -> Option<char> + Send + Sync,
+{
+    fn encode(&self, letter: char) -> Option<Vec<MorseUnit>> {
+        (self.0)(letter)
+    }
+
+    fn decode(&self, m_char: &[MorseUnit]) -> Option<char> {
+        (self.1)(m_char.to_vec())
+    }
+
+    fn symbols(&self) -> Vec<char> {
+        probe_symbols(&self.1)
+    }
+}
+
+impl fmt::Debug for dyn MorseAlphabet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MorseAlphabet")
+            .field("symbols", &self.symbols())
+            .finish()
+    }
+}
+
+// Longest code of dots and lines that is tried while listing symbols
+const PROBE_LENGTH: u32 = 7;
+
+// Conversion functions can't list own characters, so every code of dots and lines
+// up to PROBE_LENGTH and whitespace is decoded
+pub(crate) fn probe_symbols(decode: impl Fn(Vec<MorseUnit>) -> Option<char>) -> Vec<char> {
+    let codes = (1..=PROBE_LENGTH).flat_map(|len| {
+        (0..1u32 << len).map(move |bits| {
+            (0..len)
+                .map(|i| match bits >> (len - 1 - i) & 1 {
+                    0 => MorseUnit::Dot,
+                    _ => MorseUnit::Line,
+                })
+                .collect()
+        })
+    });
+
+    let mut symbols: Vec<char> = std::iter::once(vec![MorseUnit::Whitespace])
+        .chain(codes)
+        .filter_map(decode)
+        .collect();
+    symbols.sort_unstable();
+    symbols.dedup();
+
+    symbols
+}
+
+#[cfg(test)]
+mod morse_alphabet_tests {
+    use super::*;
+    use std::collections::HashMap;
+    use MorseUnit::{Dot, Line};
+
+    #[test]
+    fn closures_capture_state() {
+        let map = HashMap::from([('a', vec![Dot, Line]), ('b', vec![Line])]);
+        let reverse: HashMap<Vec<MorseUnit>, char> = map
+            .iter()
+            .map(|(letter, m_char)| (m_char.clone(), *letter))
+            .collect();
+        let alphabet = (
+            move |letter: char| map.get(&letter).cloned(),
+            move |m_char: Vec<MorseUnit>| reverse.get(&m_char).copied(),
+        );
+
+        assert_eq!(alphabet.encode('a'), Some(vec![Dot, Line]));
+        assert_eq!(alphabet.decode(&[Line]), Some('b'));
+        assert_eq!(alphabet.decode(&[Dot]), None);
+        assert_eq!(alphabet.symbols(), vec!['a', 'b']);
+    }
+}
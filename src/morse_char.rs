@@ -1,80 +1,138 @@
-use std::{thread, time};
-
-use crate::sound::TSound;
+use std::fmt;
 
 // use super::MorseUnit::Whitespace;
-use super::{convert_from_bin, DisplayChars, MorseUnit, Sound};
+use super::{convert_from_bin, Alphabet, DisplayChars, MorseError, MorseUnit, Prosign};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Symbol {
+    Letter(char),
+    Digraph(String),
+    Prosign(Prosign),
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Symbol::Letter(letter) => write!(f, "{letter}"),
+            Symbol::Digraph(digraph) => f.write_str(digraph),
+            Symbol::Prosign(prosign) => write!(f, "{prosign}"),
+        }
+    }
+}
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct MorseChar {
     m_char: Vec<MorseUnit>,
-    letter: char,
+    symbol: Symbol,
     language: String,
     display_as: DisplayChars,
-    sound: Sound,
 }
 
 impl MorseChar {
     pub fn from_char(
         letter: char,
         language: &str,
-        converter: fn(char) -> Vec<MorseUnit>,
-    ) -> MorseChar {
-        let m_char: Vec<MorseUnit> = converter(letter);
+        alphabet: &Alphabet,
+    ) -> Result<MorseChar, MorseError> {
+        let m_char: Vec<MorseUnit> = alphabet.encode(letter).ok_or(MorseError::UnknownChar {
+            letter,
+            position: 0,
+        })?;
 
-        MorseChar {
+        Ok(MorseChar {
             m_char,
-            letter,
+            symbol: Symbol::Letter(letter),
             language: language.to_string(),
             display_as: DisplayChars::default(),
-            sound: Sound::default(),
-        }
+        })
     }
 
-    pub fn from_bin(
-        letter: &str,
+    // Character without own code is sent as its parts, e.g. voiced kana
+    // as base kana and voiced mark
+    pub fn from_char_parts(
+        letter: char,
         language: &str,
-        into_char: fn(Vec<MorseUnit>) -> char,
-    ) -> MorseChar {
-        let m_char: Vec<MorseUnit> = convert_from_bin(letter);
+        alphabet: &Alphabet,
+    ) -> Result<Vec<MorseChar>, MorseError> {
+        match MorseChar::from_char(letter, language, alphabet) {
+            Ok(m_char) => Ok(vec![m_char]),
+            Err(err) => match alphabet.decompose(letter) {
+                Some(parts) => parts
+                    .chars()
+                    .map(|part| MorseChar::from_char(part, language, alphabet))
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| err),
+                None => Err(err),
+            },
+        }
+    }
 
+    pub fn new(letter: char, language: &str, m_char: Vec<MorseUnit>) -> MorseChar {
         MorseChar {
-            m_char: m_char.clone(),
-            letter: into_char(m_char),
+            m_char,
+            symbol: Symbol::Letter(letter),
             language: language.to_string(),
             display_as: DisplayChars::default(),
-            sound: Sound::default(),
         }
     }
 
-    pub fn to_beep(&self) {
-        for (idx, m_unit) in self.m_char.iter().enumerate() {
-            let _ = match m_unit {
-                MorseUnit::Dot => {
-                    self.sound.play(self.sound.frequency, 1, self.sound.speed);
-                }
-                MorseUnit::Line => {
-                    self.sound.play(self.sound.frequency, 3, self.sound.speed);
-                }
-                MorseUnit::Whitespace => {
-                    std::thread::sleep(std::time::Duration::from_secs(1));
-                }
-            };
+    pub fn from_digraph(digraph: &str, language: &str, m_char: Vec<MorseUnit>) -> MorseChar {
+        MorseChar {
+            m_char,
+            symbol: Symbol::Digraph(digraph.to_string()),
+            language: language.to_string(),
+            display_as: DisplayChars::default(),
+        }
+    }
 
-            // The space between parts of the same letter is one unit
-            if idx < self.m_char.len() - 1 {
-                thread::sleep(time::Duration::from_secs(1));
-            }
+    pub fn from_prosign(prosign: Prosign, language: &str, alphabet: &Alphabet) -> MorseChar {
+        MorseChar {
+            m_char: alphabet.encode_prosign(prosign),
+            symbol: Symbol::Prosign(prosign),
+            language: language.to_string(),
+            display_as: DisplayChars::default(),
         }
     }
 
+    pub fn from_bin(
+        letter: &str,
+        language: &str,
+        alphabet: &Alphabet,
+    ) -> Result<MorseChar, MorseError> {
+        let m_char: Vec<MorseUnit> = convert_from_bin(letter).ok_or(MorseError::InvalidBinary {
+            group: letter.to_string(),
+            position: 0,
+        })?;
+        // Letters take precedence over prosigns with the same code
+        let symbol = match (alphabet.decode(&m_char), alphabet.decode_digraph(&m_char)) {
+            (Some(letter), _) => Symbol::Letter(letter),
+            (None, Some(digraph)) => Symbol::Digraph(digraph.to_string()),
+            (None, None) => Symbol::Prosign(alphabet.decode_prosign(&m_char).ok_or(
+                MorseError::UnknownCode {
+                    code: m_char.clone(),
+                    position: 0,
+                },
+            )?),
+        };
+
+        Ok(MorseChar {
+            m_char,
+            symbol,
+            language: language.to_string(),
+            display_as: DisplayChars::default(),
+        })
+    }
+
     pub fn to_bin_str(&self) -> String {
         let mut string = String::new();
         for (idx, m_unit) in self.m_char.iter().enumerate() {
             match m_unit {
-                MorseUnit::Dot => string.push_str("1"),
+                MorseUnit::Dot => string.push('1'),
                 MorseUnit::Line => string.push_str("111"),
-                MorseUnit::Whitespace => string.push_str("0"),
+                MorseUnit::Whitespace => string.push('0'),
+                MorseUnit::LongLine => string.push_str("11111"),
+                MorseUnit::ExtraLongLine => string.push_str("1111111"),
+                MorseUnit::Gap => {}
             }
 
             // The space between parts of the same letter is one unit
@@ -98,26 +156,29 @@ impl MorseChar {
         self.display_as.whitespace = alias.to_string();
     }
 
-    pub fn frequency(&mut self, frequency: f32) {
-        self.sound.frequency = frequency;
+    pub fn get_symbol(&self) -> &Symbol {
+        &self.symbol
     }
-    pub fn play_speed(&mut self, speed: f32) {
-        self.sound.speed = speed;
+
+    pub fn get_language(&self) -> &str {
+        &self.language
     }
 
-    pub fn get_letter(&self) -> char {
-        self.letter
+    pub fn is_empty(&self) -> bool {
+        self.m_char.is_empty()
     }
 }
 
-impl ToString for MorseChar {
-    fn to_string(&self) -> String {
-        let mut string = String::new();
+impl fmt::Display for MorseChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for (idx, m_unit) in self.m_char.iter().enumerate() {
             match m_unit {
-                MorseUnit::Dot => string.push_str(&self.display_as.dot),
-                MorseUnit::Line => string.push_str(&self.display_as.line),
-                MorseUnit::Whitespace => string.push_str(&self.display_as.whitespace),
+                MorseUnit::Dot => f.write_str(&self.display_as.dot)?,
+                MorseUnit::Line => f.write_str(&self.display_as.line)?,
+                MorseUnit::Whitespace => f.write_str(&self.display_as.whitespace)?,
+                MorseUnit::LongLine => f.write_str(&self.display_as.line.repeat(2))?,
+                MorseUnit::ExtraLongLine => f.write_str(&self.display_as.line.repeat(3))?,
+                MorseUnit::Gap => {}
             }
 
             // println!("dot len:{}", self.display_as.dot.len());
@@ -126,25 +187,25 @@ impl ToString for MorseChar {
 
             // The space between parts of the same letter is one unit
             if idx < self.m_char.len() - 1 {
-                string.push(' ');
+                f.write_str(" ")?;
             }
         }
 
-        string
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod morse_char_tests {
 
-    use crate::{from_int_char, into_int_char};
-
     use super::*;
 
     #[test]
     fn create_from_text_str() {
         assert_eq!(
-            MorseChar::from_char('H', "International", from_int_char).to_bin_str(),
+            MorseChar::from_char('H', "International", &Alphabet::default())
+                .unwrap()
+                .to_bin_str(),
             "1010101"
         );
     }
@@ -153,15 +214,33 @@ mod morse_char_tests {
     fn create_from_binary_str() {
         const H_BIN: &str = "1010101";
         assert_eq!(
-            MorseChar::from_bin(H_BIN, "International", into_int_char).to_bin_str(),
+            MorseChar::from_bin(H_BIN, "International", &Alphabet::default())
+                .unwrap()
+                .to_bin_str(),
             H_BIN
         );
     }
 
+    #[test]
+    fn create_from_prosign() {
+        let m_char = MorseChar::from_prosign(Prosign::SK, "International", &Alphabet::default());
+
+        assert_eq!(m_char.to_bin_str(), "101010111010111");
+        assert_eq!(m_char.get_symbol().to_string(), "<SK>");
+        assert_eq!(
+            MorseChar::from_bin("101010111010111", "International", &Alphabet::default())
+                .unwrap()
+                .get_symbol(),
+            &Symbol::Prosign(Prosign::SK)
+        );
+    }
+
     #[test]
     fn to_string() {
         assert_eq!(
-            MorseChar::from_char('u', "International", from_int_char).to_string(),
+            MorseChar::from_char('u', "International", &Alphabet::default())
+                .unwrap()
+                .to_string(),
             ". . ⚊"
         );
     }
@@ -169,20 +248,22 @@ mod morse_char_tests {
     #[test]
     fn to_bin_str() {
         assert_eq!(
-            MorseChar::from_char('u', "International", from_int_char).to_bin_str(),
+            MorseChar::from_char('u', "International", &Alphabet::default())
+                .unwrap()
+                .to_bin_str(),
             "1010111"
         );
     }
     #[test]
     fn set_aliases_for_whitespace_lines_and_dots() {
-        let mut morse = MorseChar::from_char('u', "International", from_int_char);
+        let mut morse = MorseChar::from_char('u', "International", &Alphabet::default()).unwrap();
 
         morse.dot_as("🔥");
         morse.line_as("➖");
 
         assert_eq!(morse.to_string(), "🔥 🔥 ➖");
 
-        let mut morse = MorseChar::from_char(' ', "International", from_int_char);
+        let mut morse = MorseChar::from_char(' ', "International", &Alphabet::default()).unwrap();
 
         morse.whitespace_as("🚧");
 
@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+
+use crate::{Language, MorseAlphabet, MorseError, MorseTrie, MorseUnit, Prosign};
+
+/// ## Language definition for Morse Code.
+///
+/// Table is built from a single list of characters with their Morse Code,
+/// both directions of conversion are derived from it.
+/// # Examples
+///
+/// ```
+/// use morse_lib::{Morse, MorseTable, MorseUnit};
+/// use MorseUnit::{Dot, Line, Whitespace};
+///
+/// let table = MorseTable::new([
+///     ('а', vec![Dot, Line]),
+///     ('б', vec![Line, Dot, Dot, Dot]),
+///     ('в', vec![Dot, Line, Line]),
+///     ('г', vec![Line, Line, Dot]),
+///     (' ', vec![Whitespace]),
+/// ]).unwrap();
+///
+/// let mut morse = Morse::with_alphabet("Ukrainian".to_string(), table);
+/// morse.push_text("Баба");
+///
+/// assert_eq!(morse.to_text(), "Баба");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MorseTable {
+    from_char: HashMap<char, Vec<MorseUnit>>,
+    into_char: MorseTrie,
+    aliases: HashMap<char, char>,
+    prosigns: HashMap<Prosign, Vec<MorseUnit>>,
+    digraphs: Vec<(String, Vec<MorseUnit>)>,
+    shifts: Vec<(Prosign, Language)>,
+    decompose: Option<fn(char) -> Option<String>>,
+    compose: Option<fn(&str) -> String>,
+}
+
+impl MorseTable {
+    /// Creates table from characters with their Morse Code.
+    ///
+    /// Returns error if a character or a code is repeated, or code is invalid:
+    /// it must be not empty and [MorseUnit::Whitespace] is allowed only as
+    /// the whole code, because it can't be told apart from the space between words.
+    /// [MorseUnit::Gap] is allowed only between other units, single one in a row,
+    /// and units before every gap must be a code of the table too, as spaced letters
+    /// of American Morse Code are, e.g. O is E, gap, E. Codes without gaps may be
+    /// prefixes of each other, letters are told apart by the space between them.
+    /// Position in the error is the index of the pair.
+    pub fn new<I>(pairs: I) -> Result<MorseTable, MorseError>
+    where
+        I: IntoIterator<Item = (char, Vec<MorseUnit>)>,
+    {
+        let mut from_char = HashMap::new();
+        let mut into_char = MorseTrie::new();
+        // Codes with gaps are checked after all codes are inserted
+        let mut spaced = Vec::new();
+
+        for (position, (letter, m_char)) in pairs.into_iter().enumerate() {
+            let is_whitespace = m_char == [MorseUnit::Whitespace];
+            let misplaced_gap = m_char.first() == Some(&MorseUnit::Gap)
+                || m_char.last() == Some(&MorseUnit::Gap)
+                || m_char
+                    .windows(2)
+                    .any(|units| units == [MorseUnit::Gap, MorseUnit::Gap]);
+            if m_char.is_empty()
+                || misplaced_gap
+                || (!is_whitespace && m_char.contains(&MorseUnit::Whitespace))
+            {
+                return Err(MorseError::InvalidCode { letter, position });
+            }
+            if from_char.contains_key(&letter) {
+                return Err(MorseError::DuplicateChar { letter, position });
+            }
+            if into_char.decode(&m_char).is_some() {
+                return Err(MorseError::DuplicateCode {
+                    code: m_char,
+                    position,
+                });
+            }
+
+            into_char.insert(&m_char, letter);
+            if m_char.contains(&MorseUnit::Gap) {
+                spaced.push((position, letter, m_char.clone()));
+            }
+            from_char.insert(letter, m_char);
+        }
+
+        for (position, letter, m_char) in spaced {
+            let prefix_is_code = m_char
+                .iter()
+                .enumerate()
+                .filter(|(_, unit)| **unit == MorseUnit::Gap)
+                .all(|(gap, _)| into_char.decode(&m_char[..gap]).is_some());
+            if !prefix_is_code {
+                return Err(MorseError::InvalidCode { letter, position });
+            }
+        }
+
+        Ok(MorseTable {
+            from_char,
+            into_char,
+            aliases: HashMap::new(),
+            prosigns: HashMap::new(),
+            digraphs: Vec::new(),
+            shifts: Vec::new(),
+            decompose: None,
+            compose: None,
+        })
+    }
+
+    /// Encode alias with the code of letter from the table.
+    /// Decoding gives the letter.
+    pub fn alias(&mut self, alias: char, letter: char) {
+        self.aliases.insert(alias, letter);
+    }
+
+    /// Use own code for prosign instead of the International one.
+    pub fn prosign(&mut self, prosign: Prosign, m_char: Vec<MorseUnit>) {
+        self.prosigns.insert(prosign, m_char);
+    }
+
+    /// Encode two or more letters with single code, e.g. German CH.
+    /// Digraphs are matched case-insensitively, decoding gives lowercase digraph.
+    pub fn digraph(&mut self, digraph: &str, m_char: Vec<MorseUnit>) {
+        self.digraphs.push((digraph.to_lowercase(), m_char));
+    }
+
+    /// Switch to another language after prosign, e.g. `<DO>` switches to Wabun code.
+    /// While parsing text, prosign is inserted automatically before the character
+    /// that only the other language could encode.
+    pub fn shift(&mut self, prosign: Prosign, language: Language) {
+        self.shifts.push((prosign, language));
+    }
+
+    /// Set function that splits character without own code into characters of the table,
+    /// e.g. voiced kana into base kana and voiced mark.
+    pub fn decompose_with(&mut self, decompose: fn(char) -> Option<String>) {
+        self.decompose = Some(decompose);
+    }
+
+    /// Set function that joins decoded characters back, e.g. base kana and voiced mark
+    /// into voiced kana.
+    pub fn compose_with(&mut self, compose: fn(&str) -> String) {
+        self.compose = Some(compose);
+    }
+
+    /// Return Morse Code of character. Uppercase letters are encoded
+    /// as lowercase ones, if table has no entry for them.
+    pub fn encode(&self, letter: char) -> Option<Vec<MorseUnit>> {
+        let mut lowercase = letter.to_lowercase();
+        let lowercase = match (lowercase.next(), lowercase.next()) {
+            (Some(lowercase), None) => lowercase,
+            _ => letter,
+        };
+
+        [letter, lowercase]
+            .into_iter()
+            .flat_map(|letter| [Some(letter), self.aliases.get(&letter).copied()])
+            .flatten()
+            .find_map(|letter| self.from_char.get(&letter).cloned())
+    }
+
+    /// Return character of Morse Code.
+    pub fn decode(&self, m_char: &[MorseUnit]) -> Option<char> {
+        self.into_char.decode(m_char)
+    }
+
+    /// Return decoding tree of the table.
+    pub fn trie(&self) -> &MorseTrie {
+        &self.into_char
+    }
+
+    /// Return length in characters and Morse Code of digraph at the start of text.
+    pub fn encode_digraph(&self, letters: &[char]) -> Option<(usize, Vec<MorseUnit>)> {
+        self.digraphs.iter().find_map(|(digraph, m_char)| {
+            let len = digraph.chars().count();
+            let start: String = letters.get(..len)?.iter().collect();
+
+            (start.to_lowercase() == *digraph).then(|| (len, m_char.clone()))
+        })
+    }
+
+    /// Return digraph of Morse Code.
+    pub fn decode_digraph(&self, m_char: &[MorseUnit]) -> Option<&str> {
+        self.digraphs
+            .iter()
+            .find(|(_, code)| code.as_slice() == m_char)
+            .map(|(digraph, _)| digraph.as_str())
+    }
+
+    /// Return Morse Code of prosign.
+    pub fn encode_prosign(&self, prosign: Prosign) -> Vec<MorseUnit> {
+        self.prosigns
+            .get(&prosign)
+            .cloned()
+            .unwrap_or_else(|| prosign.code())
+    }
+
+    /// Return prosign of Morse Code.
+    pub fn decode_prosign(&self, m_char: &[MorseUnit]) -> Option<Prosign> {
+        self.prosigns
+            .iter()
+            .find(|(_, code)| code.as_slice() == m_char)
+            .map(|(prosign, _)| *prosign)
+            .or_else(|| {
+                Prosign::from_code(m_char).filter(|prosign| !self.prosigns.contains_key(prosign))
+            })
+    }
+
+    /// Return language that prosign switches to.
+    pub fn shift_of(&self, prosign: Prosign) -> Option<Language> {
+        self.shifts
+            .iter()
+            .find(|(shift, _)| *shift == prosign)
+            .map(|(_, language)| *language)
+    }
+
+    /// Return all prosigns that switch language.
+    pub fn shifts(&self) -> &[(Prosign, Language)] {
+        &self.shifts
+    }
+
+    /// Split character into characters of the table.
+    pub fn decompose(&self, letter: char) -> Option<String> {
+        self.decompose.and_then(|decompose| decompose(letter))
+    }
+
+    /// Join decoded characters.
+    pub fn compose(&self, text: &str) -> String {
+        match self.compose {
+            Some(compose) => compose(text),
+            None => text.to_string(),
+        }
+    }
+
+    /// Return all characters of the table, sorted.
+    pub fn symbols(&self) -> Vec<char> {
+        let mut symbols: Vec<char> = self.from_char.keys().copied().collect();
+        symbols.sort_unstable();
+
+        symbols
+    }
+
+    /// Return all characters of the table with their Morse Code.
+    pub fn pairs(&self) -> impl Iterator<Item = (char, &[MorseUnit])> + '_ {
+        self.from_char
+            .iter()
+            .map(|(letter, m_char)| (*letter, m_char.as_slice()))
+    }
+}
+
+// Functions that split and join characters are not compared,
+// tables are equal when they encode and decode the same way
+impl PartialEq for MorseTable {
+    fn eq(&self, other: &Self) -> bool {
+        self.from_char == other.from_char
+            && self.into_char == other.into_char
+            && self.aliases == other.aliases
+            && self.prosigns == other.prosigns
+            && self.digraphs == other.digraphs
+            && self.shifts == other.shifts
+    }
+}
+
+impl MorseAlphabet for MorseTable {
+    fn encode(&self, letter: char) -> Option<Vec<MorseUnit>> {
+        MorseTable::encode(self, letter)
+    }
+
+    fn decode(&self, m_char: &[MorseUnit]) -> Option<char> {
+        MorseTable::decode(self, m_char)
+    }
+
+    fn symbols(&self) -> Vec<char> {
+        MorseTable::symbols(self)
+    }
+}
+
+#[cfg(test)]
+mod morse_table_tests {
+    use super::*;
+    use MorseUnit::{Dot, Line, Whitespace};
+
+    #[test]
+    fn encode_and_decode() {
+        let mut table = MorseTable::new([
+            ('a', vec![Dot, Line]),
+            ('Ж', vec![Dot, Dot, Dot, Line]),
+            (' ', vec![Whitespace]),
+        ])
+        .unwrap();
+        table.alias('á', 'a');
+
+        assert_eq!(table.encode('A'), Some(vec![Dot, Line]));
+        assert_eq!(table.encode('Á'), Some(vec![Dot, Line]));
+        assert_eq!(table.encode('Ж'), Some(vec![Dot, Dot, Dot, Line]));
+        assert_eq!(table.encode('ж'), None);
+        assert_eq!(table.decode(&[Dot, Line]), Some('a'));
+        assert_eq!(table.decode(&[Whitespace]), Some(' '));
+        assert_eq!(table.decode(&[Line]), None);
+        assert_eq!(table.symbols(), vec![' ', 'a', 'Ж']);
+    }
+
+    #[test]
+    fn own_prosign_codes() {
+        let mut table = MorseTable::new([('a', vec![Dot, Line])]).unwrap();
+        table.prosign(Prosign::SK, vec![Line, Line, Line, Line]);
+
+        assert_eq!(table.encode_prosign(Prosign::SK), vec![Line; 4]);
+        assert_eq!(table.encode_prosign(Prosign::AR), Prosign::AR.code());
+        assert_eq!(table.decode_prosign(&[Line; 4]), Some(Prosign::SK));
+        assert_eq!(table.decode_prosign(&Prosign::SK.code()), None);
+        assert_eq!(table.decode_prosign(&Prosign::AR.code()), Some(Prosign::AR));
+    }
+
+    #[test]
+    fn digraphs() {
+        let mut table = MorseTable::new([('c', vec![Line, Dot, Line, Dot])]).unwrap();
+        table.digraph("CH", vec![Line; 4]);
+
+        assert_eq!(
+            table.encode_digraph(&['C', 'h', 'a']),
+            Some((2, vec![Line; 4]))
+        );
+        assert_eq!(table.encode_digraph(&['c']), None);
+        assert_eq!(table.decode_digraph(&[Line; 4]), Some("ch"));
+        assert_eq!(table.decode_digraph(&[Line]), None);
+    }
+
+    #[test]
+    fn validate_pairs() {
+        assert_eq!(
+            MorseTable::new([('a', vec![Dot, Line]), ('a', vec![Line])]),
+            Err(MorseError::DuplicateChar {
+                letter: 'a',
+                position: 1
+            })
+        );
+        assert_eq!(
+            MorseTable::new([('a', vec![Dot, Line]), ('b', vec![Dot, Line])]),
+            Err(MorseError::DuplicateCode {
+                code: vec![Dot, Line],
+                position: 1
+            })
+        );
+        assert_eq!(
+            MorseTable::new([('a', vec![])]),
+            Err(MorseError::InvalidCode {
+                letter: 'a',
+                position: 0
+            })
+        );
+        assert_eq!(
+            MorseTable::new([('a', vec![Dot, MorseUnit::Gap]), ('b', vec![Dot])]),
+            Err(MorseError::InvalidCode {
+                letter: 'a',
+                position: 0
+            })
+        );
+        assert_eq!(
+            MorseTable::new([('a', vec![Dot]), ('b', vec![Dot, Whitespace])]),
+            Err(MorseError::InvalidCode {
+                letter: 'b',
+                position: 1
+            })
+        );
+        assert_eq!(
+            MorseTable::new([
+                ('e', vec![Dot]),
+                ('o', vec![Dot, MorseUnit::Gap, Dot]),
+                ('c', vec![Dot, Dot, MorseUnit::Gap, Dot]),
+            ]),
+            Err(MorseError::InvalidCode {
+                letter: 'c',
+                position: 2
+            })
+        );
+        assert!(
+            MorseTable::new([('o', vec![Dot, MorseUnit::Gap, Dot]), ('e', vec![Dot]),]).is_ok()
+        );
+    }
+}
@@ -0,0 +1,348 @@
+use std::collections::VecDeque;
+
+use crate::morse_processors::{convert_from_bin, split_bin};
+use crate::{Alphabet, MorseError, MorseUnit};
+
+// Edges of every node, one for each unit
+const UNITS: usize = 6;
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    letter: Option<char>,
+    // Index of child node, zero is the root so it never is a child
+    children: [u32; UNITS],
+}
+
+/// ## Dichotomic decoding tree of Morse Code.
+///
+/// Every [MorseUnit] walks one edge from the root, node at the end of the code
+/// holds its character, so decoding takes as many steps as the code is long.
+/// Tree could be walked incrementally with [TrieWalker] as units arrive.
+/// # Examples
+///
+/// ```
+/// use morse_lib::{Alphabet, MorseTrie, MorseUnit};
+/// use MorseUnit::{Dot, Line};
+///
+/// let trie = MorseTrie::from(&Alphabet::default());
+///
+/// assert_eq!(trie.decode(&[Dot, Line]), Some('a'));
+/// assert_eq!(trie.decode_bin("1010101000100010111010100010111010100011101110111").unwrap(), "hello");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MorseTrie {
+    nodes: Vec<Node>,
+}
+
+impl MorseTrie {
+    /// Creates empty tree.
+    pub fn new() -> MorseTrie {
+        MorseTrie {
+            nodes: vec![Node::default()],
+        }
+    }
+
+    /// Add character with its Morse Code. Returns character that had the code before.
+    pub fn insert(&mut self, m_char: &[MorseUnit], letter: char) -> Option<char> {
+        let mut node = 0;
+        for unit in m_char {
+            let edge = edge(*unit);
+            node = match self.nodes[node].children[edge] {
+                0 => {
+                    self.nodes.push(Node::default());
+                    let child = self.nodes.len() - 1;
+                    self.nodes[node].children[edge] = child as u32;
+                    child
+                }
+                child => child as usize,
+            };
+        }
+
+        self.nodes[node].letter.replace(letter)
+    }
+
+    /// Return character of Morse Code.
+    pub fn decode(&self, m_char: &[MorseUnit]) -> Option<char> {
+        let mut walker = self.walker();
+        for unit in m_char {
+            if !walker.push(*unit) {
+                return None;
+            }
+        }
+
+        walker.letter()
+    }
+
+    /// Return all characters whose code starts with the units, shortest codes first.
+    pub fn candidates(&self, m_char: &[MorseUnit]) -> Vec<char> {
+        let mut walker = self.walker();
+        for unit in m_char {
+            if !walker.push(*unit) {
+                return Vec::new();
+            }
+        }
+
+        walker.candidates()
+    }
+
+    /// Start walking the tree from the root.
+    pub fn walker(&self) -> TrieWalker<'_> {
+        TrieWalker {
+            trie: self,
+            node: Some(0),
+        }
+    }
+
+    /// Decode binary into text without building [Morse](crate::Morse),
+    /// bits of every letter walk the tree directly.
+    /// Prosigns and digraphs are not decoded, their codes are unknown to the tree.
+    pub fn decode_bin(&self, bin: &str) -> Result<String, MorseError> {
+        let mut text = String::new();
+        let mut walker = self.walker();
+
+        for (position, letter) in split_bin(bin)? {
+            walker.reset();
+            if letter == "0" {
+                walker.push(MorseUnit::Whitespace);
+            } else {
+                for run in letter.split('0') {
+                    let unit = match run {
+                        "1" => MorseUnit::Dot,
+                        "111" => MorseUnit::Line,
+                        "11111" => MorseUnit::LongLine,
+                        "1111111" => MorseUnit::ExtraLongLine,
+                        "" => MorseUnit::Gap,
+                        _ => {
+                            return Err(MorseError::InvalidBinary {
+                                group: letter.to_string(),
+                                position,
+                            })
+                        }
+                    };
+                    if !walker.push(unit) {
+                        break;
+                    }
+                }
+            }
+
+            match walker.letter() {
+                Some(decoded) => text.push(decoded),
+                None => {
+                    return Err(MorseError::UnknownCode {
+                        code: convert_from_bin(letter).unwrap_or_default(),
+                        position,
+                    })
+                }
+            }
+        }
+
+        Ok(text)
+    }
+}
+
+// Trees are equal when they hold the same codes, whatever the order of insertion was
+impl PartialEq for MorseTrie {
+    fn eq(&self, other: &Self) -> bool {
+        let mut pending = vec![(0, 0)];
+        while let Some((node, other_node)) = pending.pop() {
+            let (node, other_node) = (&self.nodes[node], &other.nodes[other_node]);
+            if node.letter != other_node.letter {
+                return false;
+            }
+            for (child, other_child) in node.children.iter().zip(other_node.children) {
+                match (*child, other_child) {
+                    (0, 0) => {}
+                    (0, _) | (_, 0) => return false,
+                    (child, other_child) => pending.push((child as usize, other_child as usize)),
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl Default for MorseTrie {
+    fn default() -> Self {
+        MorseTrie::new()
+    }
+}
+
+impl From<&Alphabet> for MorseTrie {
+    fn from(alphabet: &Alphabet) -> Self {
+        let mut trie = MorseTrie::new();
+        for letter in alphabet.symbols() {
+            if let Some(m_char) = alphabet.encode(letter) {
+                trie.insert(&m_char, letter);
+            }
+        }
+
+        trie
+    }
+}
+
+/// ## Position in [MorseTrie] while units arrive one by one.
+#[derive(Debug, Clone)]
+pub struct TrieWalker<'a> {
+    trie: &'a MorseTrie,
+    // None after walking off the tree
+    node: Option<usize>,
+}
+
+impl TrieWalker<'_> {
+    /// Walk edge of unit. Returns false if there is no code that continues this way.
+    pub fn push(&mut self, unit: MorseUnit) -> bool {
+        self.node = self
+            .node
+            .map(|node| self.trie.nodes[node].children[edge(unit)] as usize)
+            .filter(|child| *child != 0);
+
+        self.node.is_some()
+    }
+
+    /// Return character of units walked so far.
+    pub fn letter(&self) -> Option<char> {
+        self.node.and_then(|node| self.trie.nodes[node].letter)
+    }
+
+    /// Return all characters that units walked so far could still become,
+    /// shortest codes first. E.g. after two dots it could be i, s, u, h, v, f, 5...
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::{Alphabet, MorseTrie, MorseUnit};
+    ///
+    /// let trie = MorseTrie::from(&Alphabet::default());
+    /// let mut walker = trie.walker();
+    /// walker.push(MorseUnit::Line);
+    /// walker.push(MorseUnit::Line);
+    /// walker.push(MorseUnit::Line);
+    ///
+    /// assert_eq!(walker.candidates(), vec!['o', '8', '9', '0', ':']);
+    /// ```
+    pub fn candidates(&self) -> Vec<char> {
+        let mut candidates = Vec::new();
+        let mut pending: VecDeque<usize> = self.node.into_iter().collect();
+        while let Some(node) = pending.pop_front() {
+            let node = &self.trie.nodes[node];
+            candidates.extend(node.letter);
+            pending.extend(
+                node.children
+                    .iter()
+                    .filter(|child| **child != 0)
+                    .map(|child| *child as usize),
+            );
+        }
+
+        candidates
+    }
+
+    /// Return true if no code continues units walked so far.
+    pub fn is_dead_end(&self) -> bool {
+        self.node.is_none()
+    }
+
+    /// Go back to the root for the next letter.
+    pub fn reset(&mut self) {
+        self.node = Some(0);
+    }
+}
+
+fn edge(unit: MorseUnit) -> usize {
+    match unit {
+        MorseUnit::Dot => 0,
+        MorseUnit::Line => 1,
+        MorseUnit::Whitespace => 2,
+        MorseUnit::LongLine => 3,
+        MorseUnit::ExtraLongLine => 4,
+        MorseUnit::Gap => 5,
+    }
+}
+
+#[cfg(test)]
+mod morse_trie_tests {
+    use super::*;
+    use crate::Language;
+    use MorseUnit::{Dot, Gap, Line, Whitespace};
+
+    #[test]
+    fn walk_incrementally() {
+        let trie = MorseTrie::from(&Alphabet::default());
+        let mut walker = trie.walker();
+
+        assert!(walker.push(Line));
+        assert_eq!(walker.letter(), Some('t'));
+        assert!(walker.push(Dot));
+        assert_eq!(walker.letter(), Some('n'));
+        assert!(walker.push(Line));
+        assert_eq!(walker.letter(), Some('k'));
+        walker.reset();
+        assert!(walker.push(Whitespace));
+        assert_eq!(walker.letter(), Some(' '));
+        assert!(!walker.push(Dot));
+        assert_eq!(walker.letter(), None);
+        assert!(walker.is_dead_end());
+        assert_eq!(walker.candidates(), vec![]);
+    }
+
+    #[test]
+    fn candidates() {
+        let trie = MorseTrie::from(&Alphabet::default());
+        let candidates = trie.candidates(&[Dot, Dot]);
+
+        assert_eq!(candidates[..6], ['i', 's', 'u', 'h', 'v', 'f']);
+        assert!(candidates.contains(&'5'));
+        assert!(!candidates.contains(&'a'));
+        assert_eq!(trie.candidates(&[Dot, Dot, Dot, Dot, Dot]), vec!['5']);
+        assert_eq!(trie.candidates(&[Line; 6]), vec![]);
+        assert_eq!(
+            trie.candidates(&[]).len(),
+            Alphabet::default().symbols().len()
+        );
+    }
+
+    #[test]
+    fn same_as_table() {
+        for language in Language::ALL {
+            let alphabet = Alphabet::from(*language);
+            let trie = MorseTrie::from(&alphabet);
+            for letter in alphabet.symbols() {
+                let m_char = alphabet.encode(letter).unwrap();
+                assert_eq!(trie.decode(&m_char), Some(letter));
+            }
+        }
+
+        let mut trie = MorseTrie::new();
+        assert_eq!(trie.insert(&[Dot, Gap, Dot], 'c'), None);
+        assert_eq!(trie.insert(&[Dot, Gap, Dot], 'o'), Some('c'));
+        assert_eq!(trie.decode(&[Dot, Gap]), None);
+
+        let mut other = MorseTrie::new();
+        other.insert(&[Line], 't');
+        other.insert(&[Dot, Gap, Dot], 'o');
+        assert_ne!(trie, other);
+        trie.insert(&[Line], 't');
+        assert_eq!(trie, other);
+    }
+
+    #[test]
+    fn decode_bin() {
+        let trie = MorseTrie::from(&Alphabet::from(Language::American));
+
+        assert_eq!(trie.decode_bin("1001").unwrap(), "o");
+        assert_eq!(
+            MorseTrie::from(&Alphabet::default()).decode_bin("10101010101010101"),
+            Err(MorseError::UnknownCode {
+                code: vec![Dot; 9],
+                position: 0
+            })
+        );
+        assert_eq!(
+            trie.decode_bin("11"),
+            Err(MorseError::InvalidBinary {
+                group: "11".to_string(),
+                position: 0
+            })
+        );
+    }
+}
@@ -1,7 +1,13 @@
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 /// ## Units of Morse Code.
 pub enum MorseUnit {
     Dot,
     Line,
     Whitespace, // End Of Word
+    /// Long line of American Morse Code, letter L
+    LongLine,
+    /// Longer line of American Morse Code, digit 0
+    ExtraLongLine,
+    /// Space inside letter of American Morse Code, e.g. in C, O, R, Z
+    Gap,
 }
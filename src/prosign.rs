@@ -0,0 +1,134 @@
+use std::fmt;
+
+use crate::MorseUnit::{self, Dot, Line};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+/// ## Procedural signals.
+///
+/// Prosign is sent as a single character, without the space between its letters.
+/// In text it is written as letters in angle brackets, e.g. `<SK>`.
+pub enum Prosign {
+    /// End of message, `<AR>`
+    AR,
+    /// Wait, `<AS>`
+    AS,
+    /// New paragraph, `<BT>`
+    BT,
+    /// Starting signal, `<CT>`
+    CT,
+    /// Invitation for named station to transmit, `<KN>`
+    KN,
+    /// End of work, `<SK>`
+    SK,
+    /// Understood, `<SN>`
+    SN,
+    /// Distress signal, `<SOS>`
+    SOS,
+    /// Error, `<HH>`
+    Error,
+    /// Switch to Japanese Wabun code, `<DO>`
+    DO,
+}
+
+impl Prosign {
+    /// All supported prosigns.
+    pub const ALL: [Prosign; 10] = [
+        Prosign::AR,
+        Prosign::AS,
+        Prosign::BT,
+        Prosign::CT,
+        Prosign::KN,
+        Prosign::SK,
+        Prosign::SN,
+        Prosign::SOS,
+        Prosign::Error,
+        Prosign::DO,
+    ];
+
+    /// Return letters of prosign.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::Prosign;
+    ///
+    /// assert_eq!(Prosign::Error.name(), "HH");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            Prosign::AR => "AR",
+            Prosign::AS => "AS",
+            Prosign::BT => "BT",
+            Prosign::CT => "CT",
+            Prosign::KN => "KN",
+            Prosign::SK => "SK",
+            Prosign::SN => "SN",
+            Prosign::SOS => "SOS",
+            Prosign::Error => "HH",
+            Prosign::DO => "DO",
+        }
+    }
+
+    /// Find prosign by its letters, case insensitive.
+    /// Alternative names `KA` and `VE` are accepted too.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::Prosign;
+    ///
+    /// assert_eq!(Prosign::from_name("sk"), Some(Prosign::SK));
+    /// assert_eq!(Prosign::from_name("VE"), Some(Prosign::SN));
+    /// ```
+    pub fn from_name(name: &str) -> Option<Prosign> {
+        match name.to_ascii_uppercase().as_str() {
+            "KA" => Some(Prosign::CT),
+            "VE" => Some(Prosign::SN),
+            name => Prosign::ALL
+                .into_iter()
+                .find(|prosign| prosign.name() == name),
+        }
+    }
+
+    /// Return Morse Code of prosign.
+    pub fn code(&self) -> Vec<MorseUnit> {
+        match self {
+            Prosign::AR => vec![Dot, Line, Dot, Line, Dot],
+            Prosign::AS => vec![Dot, Line, Dot, Dot, Dot],
+            Prosign::BT => vec![Line, Dot, Dot, Dot, Line],
+            Prosign::CT => vec![Line, Dot, Line, Dot, Line],
+            Prosign::KN => vec![Line, Dot, Line, Line, Dot],
+            Prosign::SK => vec![Dot, Dot, Dot, Line, Dot, Line],
+            Prosign::SN => vec![Dot, Dot, Dot, Line, Dot],
+            Prosign::SOS => vec![Dot, Dot, Dot, Line, Line, Line, Dot, Dot, Dot],
+            Prosign::Error => vec![Dot; 8],
+            Prosign::DO => vec![Line, Dot, Dot, Line, Line, Line],
+        }
+    }
+
+    /// Find prosign by its Morse Code.
+    pub fn from_code(code: &[MorseUnit]) -> Option<Prosign> {
+        Prosign::ALL
+            .into_iter()
+            .find(|prosign| prosign.code() == code)
+    }
+
+    /// Return letters of prosign with overline.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::Prosign;
+    ///
+    /// assert_eq!(Prosign::SK.overlined(), "S\u{305}K\u{305}");
+    /// ```
+    pub fn overlined(&self) -> String {
+        self.name()
+            .chars()
+            .flat_map(|letter| [letter, '\u{0305}'])
+            .collect()
+    }
+}
+
+impl fmt::Display for Prosign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}>", self.name())
+    }
+}
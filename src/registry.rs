@@ -0,0 +1,155 @@
+use std::sync::RwLock;
+
+use crate::{Alphabet, Language, MorseError};
+
+// Alphabets registered at runtime, built-in languages are not stored
+static REGISTERED: RwLock<Vec<RegistryEntry>> = RwLock::new(Vec::new());
+
+/// ## Alphabet registered under a key.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RegistryEntry {
+    /// Registry key, e.g. `uk` or `en-railroad`
+    pub key: String,
+    /// Language label
+    pub name: String,
+    pub alphabet: Alphabet,
+}
+
+/// ## Registry of alphabets.
+///
+/// Every built-in [Language] is registered under its ISO 639-1 code
+/// with optional variant, see [Language::key]. Other alphabets could be
+/// registered at runtime, then [Morse](crate::Morse) is created by the key,
+/// so stored key is enough to restore the alphabet of a message.
+/// # Examples
+///
+/// ```
+/// use morse_lib::{Morse, MorseTable, MorseUnit, Registry};
+/// use MorseUnit::{Dot, Line, Whitespace};
+///
+/// let table = MorseTable::new([
+///     ('a', vec![Dot, Line]),
+///     ('b', vec![Line, Dot, Dot, Dot]),
+///     (' ', vec![Whitespace]),
+/// ]).unwrap();
+/// Registry::register("x-ab", "AB", table).unwrap();
+///
+/// let morse = Morse::new("x-ab");
+///
+/// assert_eq!(morse.get_key(), "x-ab");
+/// assert_eq!(morse.get_language(), "AB");
+/// assert_eq!(Registry::get("uk").unwrap().name, "Ukrainian");
+/// ```
+pub struct Registry;
+
+impl Registry {
+    /// Register alphabet under the key. Returns error if the key is already taken.
+    pub fn register(
+        key: &str,
+        name: &str,
+        alphabet: impl Into<Alphabet>,
+    ) -> Result<(), MorseError> {
+        let mut registered = REGISTERED.write().unwrap_or_else(|err| err.into_inner());
+        if Language::from_key(key).is_some() || registered.iter().any(|entry| entry.key == key) {
+            return Err(MorseError::DuplicateLanguage {
+                key: key.to_string(),
+            });
+        }
+
+        registered.push(RegistryEntry {
+            key: key.to_string(),
+            name: name.to_string(),
+            alphabet: alphabet.into(),
+        });
+
+        Ok(())
+    }
+
+    /// Return alphabet registered under the key.
+    pub fn get(key: &str) -> Option<RegistryEntry> {
+        if let Some(language) = Language::from_key(key) {
+            return Some(language.into());
+        }
+
+        REGISTERED
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .iter()
+            .find(|entry| entry.key == key)
+            .cloned()
+    }
+
+    /// Return alphabet by key or by case-insensitive label.
+    pub fn find(name: &str) -> Option<RegistryEntry> {
+        Registry::get(name).or_else(|| {
+            Registry::entries()
+                .into_iter()
+                .find(|entry| entry.name.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// Return all registered alphabets, built-in languages first.
+    pub fn entries() -> Vec<RegistryEntry> {
+        let registered = REGISTERED.read().unwrap_or_else(|err| err.into_inner());
+
+        Language::ALL
+            .iter()
+            .map(|language| RegistryEntry::from(*language))
+            .chain(registered.iter().cloned())
+            .collect()
+    }
+
+    /// Return keys of all registered alphabets, built-in languages first.
+    pub fn keys() -> Vec<String> {
+        Registry::entries()
+            .into_iter()
+            .map(|entry| entry.key)
+            .collect()
+    }
+}
+
+impl From<Language> for RegistryEntry {
+    fn from(language: Language) -> Self {
+        RegistryEntry {
+            key: language.key().to_string(),
+            name: language.name().to_string(),
+            alphabet: language.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use super::*;
+    use crate::{MorseTable, MorseUnit};
+
+    #[test]
+    fn built_in_languages() {
+        let keys = Registry::keys();
+
+        // Other tests register own alphabets, so only built-in keys are checked
+        assert!(Language::ALL
+            .iter()
+            .all(|language| keys.contains(&language.key().to_string())));
+        assert!(keys.contains(&"en-railroad".to_string()));
+        assert_eq!(Registry::get("ru").unwrap().name, "Russian");
+        assert_eq!(Registry::find("greek").unwrap().key, "el");
+        assert_eq!(Registry::get("Greek"), None);
+    }
+
+    #[test]
+    fn register() {
+        let table = MorseTable::new([('a', vec![MorseUnit::Dot])]).unwrap();
+        Registry::register("x-test", "Test", table.clone()).unwrap();
+
+        assert_eq!(Registry::get("x-test").unwrap().alphabet, table.into());
+        assert!(Registry::keys().contains(&"x-test".to_string()));
+        assert_eq!(
+            Registry::register("uk", "Ukrainian", Alphabet::default()),
+            Err(MorseError::DuplicateLanguage {
+                key: "uk".to_string()
+            })
+        );
+        assert!(Registry::register("x-test", "Test", Alphabet::default()).is_err());
+    }
+}
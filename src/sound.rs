@@ -1,39 +1,102 @@
-use std::time::Duration;
+use std::{f64::consts::TAU, time::Duration};
 
-use rodio::{source::SineWave, OutputStream, Sink, Source};
+use rodio::{buffer::SamplesBuffer, OutputStream, Sink};
+
+use crate::{Envelope, Timing};
+
+// Sample rate of live playback
+const PLAYBACK_SAMPLE_RATE: u32 = 48000;
+// Peak amplitude of tone
+const AMPLITUDE: f32 = 0.20;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Sound {
     pub frequency: f32,
-    pub speed: f32,
+    pub timing: Timing,
+    pub envelope: Envelope,
 }
 
 impl Default for Sound {
     fn default() -> Self {
         Self {
             frequency: 450.0,
-            speed: 1.0,
+            timing: Timing::default(),
+            envelope: Envelope::default(),
         }
     }
 }
 
-impl TSound for Sound {}
+impl TSound for Sound {
+    fn envelope(&self) -> Envelope {
+        self.envelope
+    }
+}
 
 pub trait TSound {
-    fn play(&self, freq: f32, duration: u8, speed: f32) {
+    fn envelope(&self) -> Envelope;
+
+    // Keying is a list of tone or silence with their durations
+    fn play(&self, freq: f32, keying: &[(bool, Duration)]) {
         // on linux require pkg-config libudev-dev libasound2-dev
         // _stream must live as long as the sink
         let (_stream, stream_handle) = OutputStream::try_default().unwrap();
         let sink = Sink::try_new(&stream_handle).unwrap();
 
-        // Add a dummy source of the sake of the example.
-        let source = SineWave::new(freq)
-            .take_duration(Duration::from_secs_f32((duration as f32) / speed))
-            .amplify(0.20);
-        sink.append(source);
+        // Playback sends the same samples as rendering
+        let samples = self.render(freq, keying, PLAYBACK_SAMPLE_RATE);
+        sink.append(SamplesBuffer::new(1, PLAYBACK_SAMPLE_RATE, samples));
 
         // The sound plays in a separate thread. This call will block the current thread until the sink
         // has finished playing all its queued sounds.
         sink.sleep_until_end();
     }
+
+    // Mono samples of keyed tone
+    fn render(&self, freq: f32, keying: &[(bool, Duration)], sample_rate: u32) -> Vec<f32> {
+        let rate = f64::from(sample_rate);
+        let mut samples = Vec::new();
+        let mut elapsed = Duration::ZERO;
+
+        for (tone, duration) in keying {
+            // Bounds are rounded from elapsed time, so rounding errors don't add up
+            elapsed += *duration;
+            let end = (elapsed.as_secs_f64() * rate).round() as usize;
+            let start = samples.len().min(end);
+
+            if *tone {
+                let gains = self.envelope().gains(end - start, sample_rate);
+                samples.extend((start..end).zip(gains).map(|(idx, gain)| {
+                    AMPLITUDE * (gain * (TAU * f64::from(freq) * idx as f64 / rate).sin()) as f32
+                }));
+            } else {
+                samples.resize(end, 0.0);
+            }
+        }
+
+        samples
+    }
+}
+
+#[cfg(test)]
+mod sound_tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let sound = Sound {
+            envelope: Envelope::hard(),
+            ..Sound::default()
+        };
+        let keying = [
+            (true, Duration::from_millis(60)),
+            (false, Duration::from_millis(60)),
+            (true, Duration::from_millis(180)),
+        ];
+        let samples = sound.render(450.0, &keying, 8000);
+
+        assert_eq!(samples.len(), 2400);
+        assert!(samples[480..960].iter().all(|sample| *sample == 0.0));
+        assert!(samples[..480].iter().any(|sample| *sample > 0.19));
+        assert!(samples.iter().all(|sample| sample.abs() <= AMPLITUDE));
+    }
 }
@@ -0,0 +1,204 @@
+use std::time::Duration;
+
+// Length of the standard word PARIS with the space after it, in units
+const PARIS_UNITS: f64 = 50.0;
+
+/// ## Durations of Morse Code elements.
+///
+/// Speed is set in words per minute of the standard word PARIS, that is 50 units
+/// long with the space after it, so one unit lasts `1200 / WPM` milliseconds.
+/// - dot and intra-character space are one unit
+/// - dash and inter-character space are three units
+/// - inter-word space is seven units
+///
+/// With Farnsworth timing characters are sent at character speed, but spaces
+/// between characters and words are stretched by ARRL formula to slower effective speed.
+/// # Examples
+///
+/// ```
+/// use morse_lib::Timing;
+///
+/// let timing = Timing::new(20.0);
+///
+/// assert_eq!(timing.dot().as_millis(), 60);
+/// assert_eq!(timing.dash().as_millis(), 180);
+/// assert_eq!(timing.word_gap().as_millis(), 420);
+///
+/// let farnsworth = Timing::farnsworth(20.0, 10.0);
+///
+/// assert_eq!(farnsworth.dash().as_millis(), 180);
+/// assert_eq!(farnsworth.letter_gap().as_millis(), 653);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Timing {
+    wpm: f32,
+    effective_wpm: f32,
+}
+
+impl Timing {
+    /// Speed of [Timing::default], 20 WPM.
+    pub const DEFAULT_WPM: f32 = 20.0;
+
+    /// Creates timing with speed in words per minute.
+    /// # Panics
+    ///
+    /// Panics if speed is not a positive number.
+    pub fn new(wpm: f32) -> Timing {
+        Timing::farnsworth(wpm, wpm)
+    }
+
+    /// Creates Farnsworth timing with character speed and slower effective speed
+    /// in words per minute.
+    /// # Panics
+    ///
+    /// Panics if speed is not a positive number or effective speed is faster
+    /// than character speed.
+    pub fn farnsworth(wpm: f32, effective_wpm: f32) -> Timing {
+        assert!(wpm > 0.0 && wpm.is_finite(), "invalid speed {wpm} WPM");
+        assert!(
+            effective_wpm > 0.0 && effective_wpm <= wpm,
+            "invalid effective speed {effective_wpm} WPM"
+        );
+
+        Timing { wpm, effective_wpm }
+    }
+
+    /// Return character speed in words per minute.
+    pub fn wpm(&self) -> f32 {
+        self.wpm
+    }
+
+    /// Return effective speed in words per minute, it is slower than
+    /// character speed only with Farnsworth timing.
+    pub fn effective_wpm(&self) -> f32 {
+        self.effective_wpm
+    }
+
+    /// Return true if spaces between characters and words are stretched.
+    pub fn is_farnsworth(&self) -> bool {
+        self.effective_wpm < self.wpm
+    }
+
+    /// Return duration of one unit.
+    pub fn unit(&self) -> Duration {
+        Duration::from_secs_f64(60.0 / (PARIS_UNITS * f64::from(self.wpm)))
+    }
+
+    /// Return duration of dot, one unit.
+    pub fn dot(&self) -> Duration {
+        self.unit()
+    }
+
+    /// Return duration of dash, three units.
+    pub fn dash(&self) -> Duration {
+        self.unit() * 3
+    }
+
+    /// Return duration of space between elements of the same character, one unit.
+    pub fn element_gap(&self) -> Duration {
+        self.unit()
+    }
+
+    /// Return duration of unit of spaces between characters and words.
+    /// ARRL formula gives total delay of PARIS word `(60 * c - 37.2 * s) / (s * c)`
+    /// seconds, with c for character and s for effective speed, that is spread over
+    /// its 19 units of spaces.
+    pub fn spacing_unit(&self) -> Duration {
+        let (c, s) = (f64::from(self.wpm), f64::from(self.effective_wpm));
+
+        Duration::from_secs_f64((60.0 * c - 37.2 * s) / (s * c) / 19.0)
+    }
+
+    /// Return duration of space between characters, three units.
+    pub fn letter_gap(&self) -> Duration {
+        self.space(3)
+    }
+
+    /// Return duration of space between words, seven units.
+    pub fn word_gap(&self) -> Duration {
+        self.space(7)
+    }
+
+    // Tone of units, e.g. five for long line of American Morse Code
+    pub(crate) fn tone(&self, units: usize) -> Duration {
+        self.unit() * units as u32
+    }
+
+    // Silence of units, run of zeros in binary. Spaces inside character
+    // are shorter than three units and keep character speed
+    pub(crate) fn space(&self, units: usize) -> Duration {
+        match units {
+            0..=2 => self.unit() * units as u32,
+            _ => self.spacing_unit() * units as u32,
+        }
+    }
+
+    /// Split binary into tone and silence with their durations.
+    pub(crate) fn keying(&self, bin: &str) -> Vec<(bool, Duration)> {
+        let bits = bin.as_bytes();
+
+        bits.chunk_by(|bit, next| bit == next)
+            .map(|run| match run[0] {
+                b'1' => (true, self.tone(run.len())),
+                _ => (false, self.space(run.len())),
+            })
+            .collect()
+    }
+}
+
+impl Default for Timing {
+    fn default() -> Self {
+        Timing::new(Timing::DEFAULT_WPM)
+    }
+}
+
+#[cfg(test)]
+mod timing_tests {
+    use super::*;
+
+    #[test]
+    fn paris_standard() {
+        let timing = Timing::new(12.0);
+        let paris: Duration = timing
+            .keying("10111011101000101110001011101000101000101010000000")
+            .iter()
+            .map(|(_, duration)| *duration)
+            .sum();
+
+        assert_eq!(timing.unit(), Duration::from_millis(100));
+        assert_eq!(timing.letter_gap(), Duration::from_millis(300));
+        assert_eq!(paris, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn farnsworth() {
+        let timing = Timing::farnsworth(20.0, 10.0);
+        let paris: Duration = timing
+            .keying("10111011101000101110001011101000101000101010000000")
+            .iter()
+            .map(|(_, duration)| *duration)
+            .sum();
+
+        assert_eq!(timing.dot(), Duration::from_millis(60));
+        assert_eq!(timing.element_gap(), Duration::from_millis(60));
+        assert!((paris.as_secs_f64() - 6.0).abs() < 1e-6);
+        assert!(timing.is_farnsworth());
+        assert_eq!(Timing::new(10.0).spacing_unit(), Timing::new(10.0).unit());
+    }
+
+    #[test]
+    fn keying() {
+        let timing = Timing::new(20.0);
+
+        assert_eq!(
+            timing.keying("1110100000001"),
+            vec![
+                (true, timing.dash()),
+                (false, timing.element_gap()),
+                (true, timing.dot()),
+                (false, timing.word_gap()),
+                (true, timing.dot()),
+            ]
+        );
+    }
+}
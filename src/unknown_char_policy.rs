@@ -0,0 +1,17 @@
+use crate::MorseUnit;
+
+#[derive(Debug, PartialEq, Clone, Default)]
+/// ## What to do with characters that have no Morse Code representation.
+pub enum UnknownCharPolicy {
+    /// Stop parsing and return [MorseError::UnknownChar](crate::MorseError::UnknownChar).
+    #[default]
+    Error,
+    /// Drop the character.
+    Skip,
+    /// Encode the character with the given sequence, e.g. error prosign `........`.
+    /// Text representation keeps the original character.
+    Substitute(Vec<MorseUnit>),
+    /// Keep the character without Morse Code, so it round-trips through `to_text()`
+    /// but not through binary, string or sound output.
+    Keep,
+}
@@ -0,0 +1,190 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::Morse;
+
+// Size of RIFF header without the first 8 bytes and without samples
+const HEADER_SIZE: u32 = 36;
+// Uncompressed integer samples
+const FORMAT_PCM: u16 = 1;
+
+impl Morse {
+    /// Write Morse Code into mono RIFF/WAVE file with current frequency and [Timing](crate::Timing).
+    /// Samples are integers of 8, 16, 24 or 32 bits.
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use morse_lib::Morse;
+    ///
+    /// Morse::from_int_text("cq cq").write_wav("cq.wav", 44100, 16).unwrap();
+    /// ```
+    pub fn write_wav(&self, path: impl AsRef<Path>, sample_rate: u32, bits: u16) -> io::Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        self.write_wav_to(&mut file, sample_rate, bits)?;
+
+        file.flush()
+    }
+
+    /// Write Morse Code as RIFF/WAVE into writer, e.g. response body or buffer.
+    /// # Examples
+    ///
+    /// ```
+    /// use morse_lib::Morse;
+    ///
+    /// let mut wav = Vec::new();
+    /// Morse::from_int_text("e").write_wav_to(&mut wav, 8000, 16).unwrap();
+    ///
+    /// assert_eq!(&wav[..4], b"RIFF");
+    /// // header and 60 ms of 16-bit samples
+    /// assert_eq!(wav.len(), 44 + 480 * 2);
+    /// ```
+    pub fn write_wav_to(
+        &self,
+        mut writer: impl Write,
+        sample_rate: u32,
+        bits: u16,
+    ) -> io::Result<()> {
+        if !matches!(bits, 8 | 16 | 24 | 32) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unsupported {bits} bits per sample"),
+            ));
+        }
+
+        let block_align = bits / 8;
+        let byte_rate = sample_rate
+            .checked_mul(u32::from(block_align))
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "sample rate is too high")
+            })?;
+        let samples = self.render_samples(sample_rate);
+        let data_size = u32::try_from(samples.len() * usize::from(block_align))
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "message is too long"))?;
+        // Chunk of odd size is followed by pad byte, it counts only in RIFF size
+        let pad = data_size % 2;
+        let riff_size = HEADER_SIZE
+            .checked_add(data_size)
+            .and_then(|size| size.checked_add(pad))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "message is too long"))?;
+
+        writer.write_all(b"RIFF")?;
+        writer.write_all(&riff_size.to_le_bytes())?;
+        writer.write_all(b"WAVE")?;
+
+        writer.write_all(b"fmt ")?;
+        writer.write_all(&16u32.to_le_bytes())?;
+        writer.write_all(&FORMAT_PCM.to_le_bytes())?;
+        // Mono
+        writer.write_all(&1u16.to_le_bytes())?;
+        writer.write_all(&sample_rate.to_le_bytes())?;
+        writer.write_all(&byte_rate.to_le_bytes())?;
+        writer.write_all(&block_align.to_le_bytes())?;
+        writer.write_all(&bits.to_le_bytes())?;
+
+        writer.write_all(b"data")?;
+        writer.write_all(&data_size.to_le_bytes())?;
+        for sample in samples {
+            let sample = f64::from(sample.clamp(-1.0, 1.0));
+            match bits {
+                // 8-bit samples are unsigned with silence in the middle
+                8 => writer.write_all(&[(sample * 127.0 + 128.0).round() as u8])?,
+                16 => writer.write_all(&((sample * 32767.0).round() as i16).to_le_bytes())?,
+                24 => {
+                    writer.write_all(&((sample * 8388607.0).round() as i32).to_le_bytes()[..3])?
+                }
+                _ => writer.write_all(&((sample * 2147483647.0).round() as i32).to_le_bytes())?,
+            }
+        }
+        if pad == 1 {
+            writer.write_all(&[0])?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod wav_tests {
+    use super::*;
+    use crate::Timing;
+
+    fn u16_at(wav: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes([wav[offset], wav[offset + 1]])
+    }
+
+    fn u32_at(wav: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(wav[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn header() {
+        let mut morse = Morse::from_int_text("paris paris");
+        morse.timing(Timing::farnsworth(18.0, 12.0));
+
+        for bits in [8, 16, 24, 32] {
+            let mut wav = Vec::new();
+            morse.write_wav_to(&mut wav, 22050, bits).unwrap();
+
+            let block_align = u32::from(bits / 8);
+            let data_size = u32_at(&wav, 40);
+            assert_eq!(&wav[..4], b"RIFF");
+            assert_eq!(u32_at(&wav, 4) as usize, wav.len() - 8);
+            assert_eq!(&wav[8..16], b"WAVEfmt ");
+            assert_eq!(u32_at(&wav, 16), 16);
+            assert_eq!(u16_at(&wav, 20), FORMAT_PCM);
+            assert_eq!(u16_at(&wav, 22), 1);
+            assert_eq!(u32_at(&wav, 24), 22050);
+            assert_eq!(u32_at(&wav, 28), 22050 * block_align);
+            assert_eq!(u32::from(u16_at(&wav, 32)), block_align);
+            assert_eq!(u16_at(&wav, 34), bits);
+            assert_eq!(&wav[36..40], b"data");
+            assert_eq!(data_size as usize, wav.len() - 44 - data_size as usize % 2);
+
+            let seconds = f64::from(data_size / block_align) / 22050.0;
+            assert!((seconds - morse.duration().as_secs_f64()).abs() < 1.0 / 22050.0);
+        }
+    }
+
+    #[test]
+    fn odd_data_is_padded() {
+        // 60 ms at 8017 Hz is 481 samples
+        let morse = Morse::from_int_text("e");
+
+        for (bits, data_size) in [(8, 481), (24, 481 * 3)] {
+            let mut wav = Vec::new();
+            morse.write_wav_to(&mut wav, 8017, bits).unwrap();
+
+            assert_eq!(u32_at(&wav, 40), data_size);
+            assert_eq!(wav.len(), 44 + data_size as usize + 1);
+            assert_eq!(wav.last(), Some(&0));
+            assert_eq!(u32_at(&wav, 4) as usize, wav.len() - 8);
+        }
+        assert_eq!(
+            morse
+                .write_wav_to(Vec::new(), u32::MAX, 16)
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn write_file() {
+        let path = std::env::temp_dir().join("morse_lib_write_file.wav");
+        let morse = Morse::from_int_text("sos");
+        morse.write_wav(&path, 8000, 16).unwrap();
+        let wav = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut expected = Vec::new();
+        morse.write_wav_to(&mut expected, 8000, 16).unwrap();
+        assert_eq!(wav, expected);
+        assert_eq!(
+            morse.write_wav_to(Vec::new(), 8000, 12).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+}